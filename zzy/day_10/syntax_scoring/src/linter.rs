@@ -0,0 +1,172 @@
+//!
+//! Anti Helmet
+//! Advent of Code
+//! Day 10: Syntax Scoring
+//! Linter
+//!
+
+use std::fmt;
+
+#[cfg(test)]
+mod tests;
+
+/// Defines a bracket language by the pairs of characters that open & close chunks.
+pub struct Language {
+    /// Opening & closing character of each pair.
+    pub pairs: Vec<(char, char)>,
+    /// Whether characters that do not open or close chunks are skipped when linting.
+    /// If not set, such characters are reported as syntax errors.
+    pub ignore_others: bool,
+}
+impl Language {
+    /// Parse a language from the given string of opening & closing character pairs
+    /// ie. "()[]" defines a language with pairs '(' ')' and '[' ']'.
+    /// Returns None if the string does not contain an even no. of characters, or
+    /// uses a character more than once, such as a pair that opens & closes with the
+    /// same character, as chunks would be ambiguous.
+    pub fn parse(pairs_str: &str, ignore_others: bool) -> Option<Self> {
+        let chars: Vec<_> = pairs_str.chars().collect();
+        if chars.len() % 2 != 0 {
+            return None;
+        }
+        if chars
+            .iter()
+            .enumerate()
+            .any(|(i, c)| chars[i + 1..].contains(c))
+        {
+            return None;
+        }
+        Some(Self {
+            pairs: chars.chunks(2).map(|pair| (pair[0], pair[1])).collect(),
+            ignore_others,
+        })
+    }
+
+    /// Find the closing character paired with the given opening character.
+    /// Returns None if the character does not open a chunk.
+    pub fn closing(&self, opening: char) -> Option<char> {
+        self.pairs
+            .iter()
+            .find(|(open, _)| *open == opening)
+            .map(|&(_, close)| close)
+    }
+
+    /// Check whether the given character closes a chunk.
+    pub fn is_closing(&self, c: char) -> bool {
+        self.pairs.iter().any(|(_, close)| *close == c)
+    }
+}
+
+/// Tracks a chunk that has been opened but not yet closed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OpenChunk {
+    pub opening: char,
+    /// Character expected to close the chunk.
+    pub closing: char,
+    /// Column offset of the opening character in the line, starting from 0.
+    pub column: usize,
+}
+
+/// Defines the syntax errors that can occur while linting
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SyntaxError {
+    /// Corruption caused by finding an unexpected closing character at the given
+    /// column offset. Holds the innermost open chunk, which expected a different
+    /// closing character, or None for a stray closing character found when no chunk
+    /// is open.
+    Corruption {
+        column: usize,
+        found: char,
+        open_chunk: Option<OpenChunk>,
+    },
+    /// Character that neither opens nor closes a chunk at the given column offset.
+    UnknownChar { column: usize, found: char },
+    /// Incomplete syntax caused by chunks left open at the end of the line,
+    /// listed from the innermost to the outermost chunk.
+    Incomplete(Vec<OpenChunk>),
+}
+impl fmt::Display for SyntaxError {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        use SyntaxError::*;
+        match self {
+            Corruption {
+                column,
+                found,
+                open_chunk: Some(open_chunk),
+            } => write!(
+                out,
+                "col {}: expected '{}' but found '{}' (chunk opened by '{}' at col {})",
+                column + 1,
+                open_chunk.closing,
+                found,
+                open_chunk.opening,
+                open_chunk.column + 1
+            ),
+            Corruption {
+                column,
+                found,
+                open_chunk: None,
+            } => write!(
+                out,
+                "col {}: found '{}' but no chunk is open",
+                column + 1,
+                found
+            ),
+            UnknownChar { column, found } => {
+                write!(out, "col {}: unknown character '{}'", column + 1, found)
+            }
+            Incomplete(open_chunks) => {
+                let completion: String = open_chunks.iter().map(|chunk| chunk.closing).collect();
+                write!(
+                    out,
+                    "incomplete: expected '{}' (outermost chunk opened at col {})",
+                    completion,
+                    open_chunks.last().map_or(0, |chunk| chunk.column + 1)
+                )
+            }
+        }
+    }
+}
+
+/// Lint the given line for syntax errors in the given language.
+/// Closing characters found when no chunk is open are reported as corruption,
+/// instead of being skipped as if they closed a chunk.
+/// Returns the first syntax error that causes linting to fail, if any.
+pub fn lint(language: &Language, line: &str) -> Result<(), SyntaxError> {
+    // chunk stack tracking open chunks
+    let mut open_chunks = Vec::new();
+    for (column, c) in line.chars().enumerate() {
+        if let Some(closing) = language.closing(c) {
+            // open new chunk by pushing to stack
+            open_chunks.push(OpenChunk {
+                opening: c,
+                closing,
+                column,
+            });
+        } else if language.is_closing(c) {
+            // check chunk closing character is aligns with currently open chunks
+            match open_chunks.pop() {
+                Some(open_chunk) if open_chunk.closing == c => continue,
+                // data corruption: unexpected character
+                open_chunk => {
+                    return Err(SyntaxError::Corruption {
+                        column,
+                        found: c,
+                        open_chunk,
+                    })
+                }
+            }
+        } else if !language.ignore_others {
+            return Err(SyntaxError::UnknownChar { column, found: c });
+        }
+    }
+
+    // check for unclosed chunks
+    if open_chunks.is_empty() {
+        Ok(())
+    } else {
+        // unwind stack of unclosed chunks to obtain expected characters
+        open_chunks.reverse();
+        Err(SyntaxError::Incomplete(open_chunks))
+    }
+}
@@ -0,0 +1,99 @@
+//!
+//! Anti Helmet
+//! Advent of Code
+//! Day 10: Syntax Scoring
+//! Unit Tests
+//!
+
+use super::*;
+
+fn language(ignore_others: bool) -> Language {
+    Language::parse("()[]{}<>", ignore_others).unwrap()
+}
+
+#[test]
+fn test_language_parse() {
+    let language = Language::parse("()<>", false).unwrap();
+    assert_eq!(language.pairs, vec![('(', ')'), ('<', '>')]);
+    assert_eq!(language.closing('<'), Some('>'));
+    assert_eq!(language.closing(')'), None);
+    assert!(language.is_closing(')'));
+    assert!(!language.is_closing('('));
+
+    assert!(Language::parse("()<", false).is_none());
+    // pairs that open & close with the same character are ambiguous
+    assert!(Language::parse("||", false).is_none());
+    assert!(Language::parse("()(]", false).is_none());
+}
+
+#[test]
+fn test_lint_ok() {
+    assert_eq!(lint(&language(false), "[<>({}){}[([])<>]]"), Ok(()));
+    assert_eq!(lint(&language(false), ""), Ok(()));
+    assert_eq!(lint(&language(true), "(a[b]c)"), Ok(()));
+}
+
+#[test]
+fn test_lint_corruption() {
+    assert_eq!(
+        lint(&language(false), "{([(<{}[<>[]}>{[]{[(<()>"),
+        Err(SyntaxError::Corruption {
+            column: 12,
+            found: '}',
+            open_chunk: Some(OpenChunk {
+                opening: '[',
+                closing: ']',
+                column: 7,
+            }),
+        })
+    );
+    assert_eq!(
+        lint(&language(false), "{([(<{}[<>[]}>{[]{[(<()>")
+            .unwrap_err()
+            .to_string(),
+        "col 13: expected ']' but found '}' (chunk opened by '[' at col 8)"
+    );
+    let error = lint(&language(false), "[<>({}){}[([])<>]]}").unwrap_err();
+    assert_eq!(error.to_string(), "col 19: found '}' but no chunk is open");
+}
+
+#[test]
+fn test_lint_stray_closing() {
+    // stray closing characters are corruption, even before any chunk is opened
+    assert_eq!(
+        lint(&language(false), ")()"),
+        Err(SyntaxError::Corruption {
+            column: 0,
+            found: ')',
+            open_chunk: None,
+        })
+    );
+}
+
+#[test]
+fn test_lint_unknown_char() {
+    assert_eq!(
+        lint(&language(false), "(a)"),
+        Err(SyntaxError::UnknownChar {
+            column: 1,
+            found: 'a',
+        })
+    );
+}
+
+#[test]
+fn test_lint_incomplete() {
+    let error = lint(&language(false), "[({(<(())[]>[[{[]{<()<>>").unwrap_err();
+    match &error {
+        SyntaxError::Incomplete(open_chunks) => {
+            let completion: String = open_chunks.iter().map(|chunk| chunk.closing).collect();
+            assert_eq!(completion, "}}]])})]");
+            assert_eq!(open_chunks.last().unwrap().column, 0);
+        }
+        error => panic!("Expected incomplete error, got {:?}", error),
+    }
+    assert_eq!(
+        error.to_string(),
+        "incomplete: expected '}}]])})]' (outermost chunk opened at col 1)"
+    );
+}
@@ -4,101 +4,127 @@
 //! Day 10: Syntax Scoring
 //!
 
-use std::collections::VecDeque;
+use std::env;
 use std::io::{stdin, BufRead};
 
-/// Defines the syntax errors that can occur while lintiing
-enum SyntaxError {
-    /// Corruption caused by finding an unexpected character
-    Corruption(char),
-    /// Incomplete syntax caused missing closing chunk characters
-    Incomplete(Vec<char>),
-}
+mod linter;
+mod repair;
+mod scoring;
+use linter::{lint, Language, SyntaxError};
+use repair::{repair, MAX_REPAIR_CHUNK_CHARS};
+use scoring::{ScoreScheme, Scores, SCHEME_NAMES};
 
-/// Lint the given line for syntax errors
-/// Returns true if the line passes linting, otherwise returns the first syntax
-/// error that causes linting to fail.
-fn lint(line: &str) -> Result<bool, SyntaxError> {
-    // define closures mapping between opening and closing chunk chars
-    let map_closing = |opening: char| match opening {
-        '(' => ')',
-        '[' => ']',
-        '{' => '}',
-        '<' => '>',
-        _ => panic!("Unknown chunk character"),
+fn main() {
+    // parse options from command line arguments:
+    // --pairs=PAIRS to define the chunk language by its opening & closing character
+    //   pairs (default: ()[]{}<>)
+    // --ignore-others to skip characters that do not open or close chunks.
+    // --report to print the syntax error found on each line.
+    // --repair to print a minimal repair of each line with syntax errors.
+    // --corruption-scores=SCHEME to score corrupted lines by the named scheme (default: aoc)
+    // --completion-scores=SCHEME to score incomplete lines by the named scheme (default: aoc)
+    // --breakdown to print the score of each corrupted or incomplete line.
+    let (mut pairs, mut ignore_others, mut report, mut show_repair, mut breakdown) =
+        ("()[]{}<>".to_string(), false, false, false, false);
+    let (mut corruption_name, mut completion_name) = ("aoc".to_string(), "aoc".to_string());
+    for arg in env::args().skip(1) {
+        match arg.split_once('=').unwrap_or((&arg, "")) {
+            ("--pairs", value) => pairs = value.to_string(),
+            ("--ignore-others", _) => ignore_others = true,
+            ("--report", _) => report = true,
+            ("--repair", _) => show_repair = true,
+            ("--corruption-scores", value) => corruption_name = value.to_string(),
+            ("--completion-scores", value) => completion_name = value.to_string(),
+            ("--breakdown", _) => breakdown = true,
+            _ => panic!("Unsupported option: {}", arg),
+        }
+    }
+    let language = Language::parse(&pairs, ignore_others)
+        .expect("Expected chunk pairs to have an even no. of distinct characters");
+    let unknown_scheme = |name: &str| {
+        panic!(
+            "Unsupported score scheme: {}, expected one of: {}",
+            name,
+            SCHEME_NAMES.join(", ")
+        )
     };
+    let corruption_scheme = ScoreScheme::corruption(&corruption_name, &language)
+        .unwrap_or_else(|| unknown_scheme(&corruption_name));
+    let completion_scheme = ScoreScheme::completion(&completion_name, &language)
+        .unwrap_or_else(|| unknown_scheme(&completion_name));
+
+    // read, lint & score lines from stdin
+    let mut scores = Scores::default();
+    for (i, line) in stdin().lock().lines().enumerate() {
+        let line = line.expect("Failed to read lines from stdin");
+        let err = match lint(&language, &line) {
+            Ok(()) => continue,
+            Err(err) => err,
+        };
+        if report {
+            println!("line {}: {}", i + 1, err);
+        }
+        if show_repair {
+            match repair(&language, &line) {
+                Some(line_repair) => {
+                    let (marked, markers) = line_repair.marked();
+                    println!("line {}: {} edit(s)", i + 1, line_repair.n_edits());
+                    println!("  edits:    {}", marked);
+                    println!("            {}", markers.trim_end());
+                    println!("  repaired: {}", line_repair.repaired());
+                }
+                None => println!(
+                    "line {}: too long to repair, expected at most {} chunk characters",
+                    i + 1,
+                    MAX_REPAIR_CHUNK_CHARS
+                ),
+            }
+        }
 
-    // chunk stack tracking open chunks
-    let mut open_chunks = VecDeque::new();
-    for c in line.chars() {
-        match c {
-            // open new chunk by pushing to stack
-            '(' | '[' | '{' | '<' => {
-                open_chunks.push_back(map_closing(c));
+        match &err {
+            SyntaxError::Corruption { found, .. } => {
+                let score = corruption_scheme.score_corruption(&err).unwrap();
+                if breakdown {
+                    println!("line {}: corrupted by '{}' scores {}", i + 1, found, score);
+                }
+                scores.corruption_total += score;
             }
-            // check chunk closing character is aligns with currently open chunks
-            ')' | ']' | '}' | '>' => {
-                match open_chunks.pop_back() {
-                    // data corruption: unexpected character
-                    Some(expected) if c != expected => return Err(SyntaxError::Corruption(c)),
-                    _ => continue,
+            SyntaxError::Incomplete(open_chunks) => {
+                let score = match completion_scheme.score_completion(open_chunks) {
+                    Some(score) => score,
+                    None => {
+                        eprintln!("line {}: completion score overflows u64, skipped", i + 1);
+                        continue;
+                    }
+                };
+                if breakdown {
+                    let completion: String =
+                        open_chunks.iter().map(|chunk| chunk.closing).collect();
+                    let points: Vec<_> = open_chunks
+                        .iter()
+                        .map(|chunk| completion_scheme.points(chunk.closing).to_string())
+                        .collect();
+                    println!(
+                        "line {}: completed by '{}' with points {} scores {}",
+                        i + 1,
+                        completion,
+                        points.join(","),
+                        score
+                    );
+                }
+                scores.completions.push(score);
+            }
+            SyntaxError::UnknownChar { .. } => {
+                if breakdown {
+                    println!("line {}: unknown character scores 0", i + 1);
                 }
             }
-            _ => panic!("Unexpected character: {}", c),
         }
     }
 
-    // check for unclosed chunks
-    if open_chunks.is_empty() {
-        Ok(true)
-    } else {
-        // unwind stack of unclosed chunks to obtain expected characters
-        Err(SyntaxError::Incomplete(
-            open_chunks.into_iter().rev().collect(),
-        ))
+    println!("Corruption syntax error score: {}", scores.corruption_total);
+    match scores.middle_completion() {
+        Some(middle_score) => println!("Total syntax error score: {}", middle_score),
+        None => println!("No incomplete lines to score"),
     }
 }
-
-fn main() {
-    // read & lint lines from stdin
-    let errors: Vec<_> = stdin()
-        .lock()
-        .lines()
-        .map(|line| lint(&line.expect("Failed to read lines from stdin")))
-        // filter out non incomplete errors
-        .flat_map(|rst| match rst {
-            Err(err @ SyntaxError::Incomplete(_)) => Some(err),
-            _ => None,
-        })
-        .collect();
-
-    // tabulate completion string scores
-    let mut scores: Vec<u64> = errors
-        .into_iter()
-        .map(|err| {
-            let expected_chars = match err {
-                SyntaxError::Incomplete(expected) => expected,
-                _ => panic!("Unexpected syntax error type"),
-            };
-
-            let score = expected_chars
-                .into_iter()
-                .map(|c| match c {
-                    ')' => 1,
-                    ']' => 2,
-                    '}' => 3,
-                    '>' => 4,
-                    _ => panic!("Unexpected character scoring completion strings"),
-                })
-                .fold(0, |left, right| left * 5 + right);
-
-            score
-        })
-        .collect();
-
-    // find middle score
-    scores.sort_unstable();
-    let middle_score = scores[scores.len() / 2];
-
-    println!("Total syntax error score: {}", middle_score);
-}
@@ -0,0 +1,249 @@
+//!
+//! Anti Helmet
+//! Advent of Code
+//! Day 10: Syntax Scoring
+//! Repair
+//!
+
+use crate::linter::Language;
+
+#[cfg(test)]
+mod tests;
+
+/// Max no. of chunk characters in a line that can be repaired. Repairs are computed
+/// by dynamic programming over every range of chunk characters, taking O(n^3) time
+/// & O(n^2) memory for n chunk characters, so longer lines are not repaired.
+pub const MAX_REPAIR_CHUNK_CHARS: usize = 500;
+
+/// Defines a single character in a repaired line and how it was edited.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edit {
+    /// Character kept unchanged from the original line.
+    Keep(char),
+    /// Character inserted into the original line.
+    Insert(char),
+    /// Character deleted from the original line.
+    Delete(char),
+    /// Original character substituted with the replacement character.
+    Substitute { found: char, replacement: char },
+}
+
+/// Repair of a line into one with balanced chunks.
+pub struct Repair {
+    /// Edits applied to each character of the line, in order.
+    pub edits: Vec<Edit>,
+}
+impl Repair {
+    /// No. of characters inserted, deleted or substituted by the repair.
+    pub fn n_edits(&self) -> usize {
+        self.edits
+            .iter()
+            .filter(|edit| !matches!(edit, Edit::Keep(_)))
+            .count()
+    }
+
+    /// Retrieve the repaired line.
+    pub fn repaired(&self) -> String {
+        self.edits
+            .iter()
+            .flat_map(|edit| match *edit {
+                Edit::Keep(c) | Edit::Insert(c) => Some(c),
+                Edit::Substitute { replacement, .. } => Some(replacement),
+                Edit::Delete(_) => None,
+            })
+            .collect()
+    }
+
+    /// Render the original line with insertions in place, aligned with a line of
+    /// markers: '+' for inserted, '-' for deleted and '~' for substituted characters.
+    pub fn marked(&self) -> (String, String) {
+        self.edits
+            .iter()
+            .map(|edit| match *edit {
+                Edit::Keep(c) => (c, ' '),
+                Edit::Insert(c) => (c, '+'),
+                Edit::Delete(c) => (c, '-'),
+                Edit::Substitute { found, .. } => (found, '~'),
+            })
+            .unzip()
+    }
+}
+
+/// Strategy used to balance the chunk at the start of a range of chunk characters.
+#[derive(Debug, Clone, Copy)]
+enum Choice {
+    /// Range is empty and already balanced.
+    Empty,
+    /// Insert the character that pairs with the first character. Costs the same
+    /// as deleting the first character, so deletion is never needed.
+    Insert,
+    /// Pair the first character with the character at the given index,
+    /// substituting characters as needed.
+    Pair(usize),
+}
+
+/// Computes minimal repairs of the chunk characters in a line.
+struct Repairer<'a> {
+    language: &'a Language,
+    chunk_chars: Vec<char>,
+    /// Choice made to balance each range of chunk characters at minimal cost,
+    /// indexed by range start & end.
+    choices: Vec<Vec<Choice>>,
+}
+impl<'a> Repairer<'a> {
+    /// Check whether the given character opens a chunk.
+    fn is_opening(&self, c: char) -> bool {
+        self.language.closing(c).is_some()
+    }
+
+    /// Find the opening character paired with the given closing character.
+    fn opening(&self, closing: char) -> char {
+        self.language
+            .pairs
+            .iter()
+            .find(|(_, close)| *close == closing)
+            .map(|&(open, _)| open)
+            .unwrap()
+    }
+
+    /// Find the replacements for the given characters that make them a pair,
+    /// using as few substitutions as possible.
+    fn pair_with(&self, left: char, right: char) -> (char, char) {
+        match (self.is_opening(left), self.is_opening(right)) {
+            (true, _) => (left, self.language.closing(left).unwrap()),
+            (false, false) => (self.opening(right), right),
+            (false, true) => {
+                let (opening, closing) = self.language.pairs[0];
+                (opening, closing)
+            }
+        }
+    }
+
+    /// Compute the minimal no. of edits to balance the chunk characters.
+    /// Uses dynamic programming over ranges of chunk characters, where each range
+    /// is balanced by inserting a character to pair with its first character,
+    /// or pairing it with a later character in the range, substituting if needed.
+    fn solve(&mut self) -> u32 {
+        let n_chars = self.chunk_chars.len();
+        let mut costs = vec![vec![0u32; n_chars + 1]; n_chars + 1];
+        self.choices = vec![vec![Choice::Empty; n_chars + 1]; n_chars + 1];
+
+        for len in 1..=n_chars {
+            for begin in 0..=n_chars - len {
+                let end = begin + len;
+                let mut best = (costs[begin + 1][end] + 1, Choice::Insert);
+                for pair_i in begin + 1..end {
+                    let (left, right) = (self.chunk_chars[begin], self.chunk_chars[pair_i]);
+                    let (new_left, new_right) = self.pair_with(left, right);
+                    let n_substitutes = (left != new_left) as u32 + (right != new_right) as u32;
+                    let cost = n_substitutes + costs[begin + 1][pair_i] + costs[pair_i + 1][end];
+                    if cost < best.0 {
+                        best = (cost, Choice::Pair(pair_i));
+                    }
+                }
+                costs[begin][end] = best.0;
+                self.choices[begin][end] = best.1;
+            }
+        }
+        costs[0][n_chars]
+    }
+
+    /// Reconstruct the edits chosen to balance the range of chunk characters
+    /// from begin to end, appending them to edits with the index of the chunk
+    /// character each edit applies to, None for inserted characters.
+    fn edits(&self, begin: usize, end: usize, edits: &mut Vec<(Option<usize>, Edit)>) {
+        let first = self.chunk_chars.get(begin).copied();
+        match self.choices[begin][end] {
+            Choice::Empty => (),
+            Choice::Insert => {
+                let first = first.unwrap();
+                if self.is_opening(first) {
+                    // wrap the rest of the range in the chunk opened by the first character
+                    edits.push((Some(begin), Edit::Keep(first)));
+                    self.edits(begin + 1, end, edits);
+                    edits.push((None, Edit::Insert(self.language.closing(first).unwrap())));
+                } else {
+                    edits.push((None, Edit::Insert(self.opening(first))));
+                    edits.push((Some(begin), Edit::Keep(first)));
+                    self.edits(begin + 1, end, edits);
+                }
+            }
+            Choice::Pair(pair_i) => {
+                let (left, right) = (first.unwrap(), self.chunk_chars[pair_i]);
+                let (new_left, new_right) = self.pair_with(left, right);
+                let edit = |found, replacement| {
+                    if found == replacement {
+                        Edit::Keep(found)
+                    } else {
+                        Edit::Substitute { found, replacement }
+                    }
+                };
+                edits.push((Some(begin), edit(left, new_left)));
+                self.edits(begin + 1, pair_i, edits);
+                edits.push((Some(pair_i), edit(right, new_right)));
+                self.edits(pair_i + 1, end, edits);
+            }
+        }
+    }
+}
+
+/// Compute a repair of the given line into one with balanced chunks in the given
+/// language using the minimal no. of character insertions, deletions and substitutions.
+/// Characters that neither open nor close chunks are kept if the language ignores
+/// them and deleted otherwise.
+/// Returns None if the line has more than MAX_REPAIR_CHUNK_CHARS chunk characters.
+pub fn repair(language: &Language, line: &str) -> Option<Repair> {
+    repair_within(language, line, MAX_REPAIR_CHUNK_CHARS)
+}
+
+/// Repair the given line like repair(), limited to the given max no. of chunk characters.
+fn repair_within(language: &Language, line: &str, max_chunk_chars: usize) -> Option<Repair> {
+    let is_chunk_char = |c: char| language.closing(c).is_some() || language.is_closing(c);
+    let chunk_chars: Vec<_> = line.chars().filter(|&c| is_chunk_char(c)).collect();
+    if chunk_chars.len() > max_chunk_chars {
+        return None;
+    }
+    let mut repairer = Repairer {
+        language,
+        chunk_chars,
+        choices: Vec::new(),
+    };
+    repairer.solve();
+    let mut chunk_edits = Vec::new();
+    repairer.edits(0, repairer.chunk_chars.len(), &mut chunk_edits);
+
+    // interleave the other characters with the chunk edits in their original positions
+    let mut other_chars = line.chars().enumerate().filter(|&(_, c)| !is_chunk_char(c));
+    let chunk_columns: Vec<_> = line
+        .chars()
+        .enumerate()
+        .filter(|&(_, c)| is_chunk_char(c))
+        .map(|(column, _)| column)
+        .collect();
+    let other_edit = |c| {
+        if language.ignore_others {
+            Edit::Keep(c)
+        } else {
+            Edit::Delete(c)
+        }
+    };
+
+    let mut edits = Vec::new();
+    let mut next_other = other_chars.next();
+    for (chunk_i, edit) in chunk_edits {
+        if let Some(chunk_i) = chunk_i {
+            while let Some((_, c)) =
+                next_other.filter(|&(column, _)| column < chunk_columns[chunk_i])
+            {
+                edits.push(other_edit(c));
+                next_other = other_chars.next();
+            }
+        }
+        edits.push(edit);
+    }
+    while let Some((_, c)) = next_other {
+        edits.push(other_edit(c));
+        next_other = other_chars.next();
+    }
+    Some(Repair { edits })
+}
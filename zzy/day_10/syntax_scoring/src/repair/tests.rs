@@ -0,0 +1,106 @@
+//!
+//! Anti Helmet
+//! Advent of Code
+//! Day 10: Syntax Scoring
+//! Unit Tests
+//!
+
+use super::*;
+use crate::linter::lint;
+
+fn language(ignore_others: bool) -> Language {
+    Language::parse("()[]{}<>", ignore_others).unwrap()
+}
+
+#[test]
+fn test_repair_balanced() {
+    let language = language(false);
+    let line_repair = repair(&language, "[<>({}){}[([])<>]]").unwrap();
+    assert_eq!(line_repair.n_edits(), 0);
+    assert_eq!(line_repair.repaired(), "[<>({}){}[([])<>]]");
+}
+
+#[test]
+fn test_repair_incomplete() {
+    let language = language(false);
+    let line_repair = repair(&language, "(<>").unwrap();
+    assert_eq!(line_repair.n_edits(), 1);
+    assert_eq!(line_repair.repaired(), "(<>)");
+    let (marked, markers) = line_repair.marked();
+    assert_eq!(marked, "(<>)");
+    assert_eq!(markers, "   +");
+
+    // substituting an opening character is cheaper than closing both chunks
+    let line_repair = repair(&language, "[<>(").unwrap();
+    assert_eq!(line_repair.n_edits(), 1);
+    assert_eq!(line_repair.repaired(), "[<>]");
+}
+
+#[test]
+fn test_repair_corrupted() {
+    let language = language(false);
+    let line_repair = repair(&language, "{()()()>").unwrap();
+    assert_eq!(line_repair.n_edits(), 1);
+    assert_eq!(line_repair.repaired(), "{()()()}");
+    assert_eq!(
+        line_repair.edits.last(),
+        Some(&Edit::Substitute {
+            found: '>',
+            replacement: '}'
+        })
+    );
+
+    // stray closing character is paired with an inserted opening character
+    let line_repair = repair(&language, "()]").unwrap();
+    assert_eq!(line_repair.n_edits(), 1);
+    assert_eq!(line_repair.repaired(), "()[]");
+}
+
+#[test]
+fn test_repair_other_chars() {
+    let line = "(a[b)c";
+    let line_repair = repair(&language(true), line).unwrap();
+    assert_eq!(line_repair.n_edits(), 1);
+    assert_eq!(line_repair.repaired(), "(a[]b)c");
+
+    let line_repair = repair(&language(false), line).unwrap();
+    assert_eq!(line_repair.n_edits(), 4);
+    assert_eq!(line_repair.repaired(), "([])");
+    assert_eq!(
+        line_repair.marked(),
+        ("(a[]b)c".to_string(), " - +- -".to_string())
+    );
+}
+
+#[test]
+fn test_repair_lints_clean() {
+    let language = language(false);
+    for line in [
+        "[({(<(())[]>[[{[]{<()<>>",
+        "{([(<{}[<>[]}>{[]{[(<()>",
+        "[[<[([]))<([[{}[[()]]]",
+        "<{([([[(<>()){}]>(<<{{",
+        ")))(((",
+    ]
+    .iter()
+    {
+        let line_repair = repair(&language, line).unwrap();
+        assert_eq!(lint(&language, &line_repair.repaired()), Ok(()));
+    }
+}
+
+#[test]
+fn test_repair_too_long() {
+    let language = language(false);
+    let line = "((((";
+    // half of the opening characters are substituted to close the other half
+    assert_eq!(repair_within(&language, line, 4).unwrap().n_edits(), 2);
+    // characters that do not open or close chunks do not count towards the limit
+    let line = format!("{}a", line);
+    assert!(repair_within(&language, &line, 4).is_some());
+    assert!(repair_within(&language, &format!("{})", line), 4).is_none());
+
+    // lines past the limit are rejected before attempting to repair them
+    let line = "(".repeat(MAX_REPAIR_CHUNK_CHARS + 1);
+    assert!(repair(&language, &line).is_none());
+}
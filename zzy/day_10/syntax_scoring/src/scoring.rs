@@ -0,0 +1,117 @@
+//!
+//! Anti Helmet
+//! Advent of Code
+//! Day 10: Syntax Scoring
+//! Scoring
+//!
+
+use crate::linter::{Language, OpenChunk, SyntaxError};
+use std::collections::HashMap;
+
+#[cfg(test)]
+mod tests;
+
+/// Names of the supported score schemes, in the order they are listed in usage.
+/// - aoc: Advent of Code's points for the ) ] } > closing characters.
+/// - position: points by the position of the closing character's pair in the language.
+/// - unit: 1 point per closing character.
+pub const SCHEME_NAMES: [&str; 3] = ["aoc", "position", "unit"];
+
+/// Scores syntax errors by the points awarded to their closing characters.
+pub struct ScoreScheme {
+    /// Points awarded to each closing character. Characters without points score 0.
+    points: HashMap<char, u64>,
+    /// Multiplier applied to the running score before adding each completion character.
+    multiplier: u64,
+}
+impl ScoreScheme {
+    /// Create the corruption score scheme with the given name for the given language.
+    /// Returns None if no scheme has the given name.
+    pub fn corruption(name: &str, language: &Language) -> Option<Self> {
+        let points = match name {
+            "aoc" => [(')', 3), (']', 57), ('}', 1197), ('>', 25137)]
+                .iter()
+                .copied()
+                .collect(),
+            _ => Self::language_points(name, language)?,
+        };
+        Some(Self {
+            points,
+            multiplier: 1,
+        })
+    }
+
+    /// Create the completion score scheme with the given name for the given language.
+    /// Returns None if no scheme has the given name.
+    pub fn completion(name: &str, language: &Language) -> Option<Self> {
+        let (points, multiplier) = match name {
+            "aoc" => (
+                [(')', 1), (']', 2), ('}', 3), ('>', 4)]
+                    .iter()
+                    .copied()
+                    .collect(),
+                5,
+            ),
+            // scale by one more than the no. of pairs so each completion string
+            // scores uniquely
+            "position" => (
+                Self::language_points(name, language)?,
+                language.pairs.len() as u64 + 1,
+            ),
+            _ => (Self::language_points(name, language)?, 1),
+        };
+        Some(Self { points, multiplier })
+    }
+
+    /// Award points to the closing characters of the given language by the named scheme.
+    fn language_points(name: &str, language: &Language) -> Option<HashMap<char, u64>> {
+        let closings = language.pairs.iter().map(|&(_, closing)| closing);
+        match name {
+            "position" => Some(closings.zip(1..).collect()),
+            "unit" => Some(closings.map(|closing| (closing, 1)).collect()),
+            _ => None,
+        }
+    }
+
+    /// Points awarded to the given closing character.
+    pub fn points(&self, closing: char) -> u64 {
+        self.points.get(&closing).copied().unwrap_or(0)
+    }
+
+    /// Score the given syntax error if it is a corruption.
+    pub fn score_corruption(&self, err: &SyntaxError) -> Option<u64> {
+        match err {
+            SyntaxError::Corruption { found, .. } => Some(self.points(*found)),
+            _ => None,
+        }
+    }
+
+    /// Score the completion string that closes the given open chunks, listed from
+    /// innermost to outermost.
+    /// Returns None if the score overflows u64, as it may for long incomplete lines.
+    pub fn score_completion(&self, open_chunks: &[OpenChunk]) -> Option<u64> {
+        open_chunks.iter().try_fold(0u64, |score, chunk| {
+            score
+                .checked_mul(self.multiplier)?
+                .checked_add(self.points(chunk.closing))
+        })
+    }
+}
+
+/// Totals of the corruption & completion scores over the linted lines.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Scores {
+    /// Sum of the corruption scores of corrupted lines.
+    pub corruption_total: u64,
+    /// Completion scores of incomplete lines.
+    pub completions: Vec<u64>,
+}
+impl Scores {
+    /// Middle completion score once sorted, None if no lines were incomplete.
+    /// Takes the higher of the two middle scores if there is an even no. of scores.
+    pub fn middle_completion(&self) -> Option<u64> {
+        let mut completions = self.completions.clone();
+        completions.sort_unstable();
+        completions.get(completions.len() / 2).copied()
+    }
+}
@@ -0,0 +1,84 @@
+//!
+//! Anti Helmet
+//! Advent of Code
+//! Day 10: Syntax Scoring
+//! Unit Tests
+//!
+
+use super::*;
+use crate::linter::lint;
+
+fn language() -> Language {
+    Language::parse("()[]{}<>", false).unwrap()
+}
+
+fn open_chunks(line: &str) -> Vec<OpenChunk> {
+    match lint(&language(), line) {
+        Err(SyntaxError::Incomplete(open_chunks)) => open_chunks,
+        rst => panic!("Expected incomplete line, got: {:?}", rst),
+    }
+}
+
+#[test]
+fn test_score_corruption() {
+    let language = language();
+    let aoc = ScoreScheme::corruption("aoc", &language).unwrap();
+    let err = lint(&language, "{([(<{}[<>[]}>{[]{[(<()>").unwrap_err();
+    assert_eq!(aoc.score_corruption(&err), Some(1197));
+    let position = ScoreScheme::corruption("position", &language).unwrap();
+    assert_eq!(position.score_corruption(&err), Some(3));
+    let unit = ScoreScheme::corruption("unit", &language).unwrap();
+    assert_eq!(unit.score_corruption(&err), Some(1));
+
+    let err = lint(&language, "[<>(").unwrap_err();
+    assert_eq!(aoc.score_corruption(&err), None);
+}
+
+#[test]
+fn test_score_completion() {
+    let language = language();
+    let aoc = ScoreScheme::completion("aoc", &language).unwrap();
+    assert_eq!(
+        aoc.score_completion(&open_chunks("[({(<(())[]>[[{[]{<()<>>")),
+        Some(288957)
+    );
+    assert_eq!(
+        aoc.score_completion(&open_chunks("<{([{{}}[<[[[<>{}]]]>[]]")),
+        Some(294)
+    );
+    assert_eq!(aoc.score_completion(&[]), Some(0));
+    // scores of long completion strings overflow u64
+    assert_eq!(aoc.score_completion(&open_chunks(&"<".repeat(28))), None);
+    assert!(aoc
+        .score_completion(&open_chunks(&"<".repeat(27)))
+        .is_some());
+
+    let position = ScoreScheme::completion("position", &language).unwrap();
+    assert_eq!(
+        position.score_completion(&open_chunks("<{([")),
+        Some(((2 * 5 + 1) * 5 + 3) * 5 + 4)
+    );
+    let unit = ScoreScheme::completion("unit", &language).unwrap();
+    assert_eq!(unit.score_completion(&open_chunks("<{([")), Some(4));
+}
+
+#[test]
+fn test_score_scheme_names() {
+    let language = language();
+    for name in SCHEME_NAMES.iter() {
+        assert!(ScoreScheme::corruption(name, &language).is_some());
+        assert!(ScoreScheme::completion(name, &language).is_some());
+    }
+    assert!(ScoreScheme::corruption("golf", &language).is_none());
+    assert!(ScoreScheme::completion("golf", &language).is_none());
+}
+
+#[test]
+fn test_middle_completion() {
+    let scores = Scores {
+        corruption_total: 0,
+        completions: vec![288957, 5566, 1480781, 995444, 294],
+    };
+    assert_eq!(scores.middle_completion(), Some(288957));
+    assert_eq!(Scores::default().middle_completion(), None);
+}
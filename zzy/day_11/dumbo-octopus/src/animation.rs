@@ -0,0 +1,104 @@
+//!
+//! Anti Helmet
+//! Advent of Code
+//! Day 11: Dumbo Octopus
+//! Terminal Animation
+//!
+
+use crate::automaton::CascadeAutomaton;
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+
+#[cfg(test)]
+mod tests;
+
+/// ANSI escape sequence to highlight cells that flashed in bold bright yellow.
+const FLASH_STYLE: &str = "\x1b[1;93m";
+/// ANSI escape sequence to dim cells that did not flash.
+const IDLE_STYLE: &str = "\x1b[2m";
+/// ANSI escape sequence to reset styling.
+const RESET_STYLE: &str = "\x1b[0m";
+
+/// Animates a cascade automaton in a terminal by redrawing its grid in place each step.
+pub struct Animation<W: Write> {
+    out: W,
+    /// Delay between each frame.
+    delay: Duration,
+    /// No. of lines drawn by the last frame, to move the cursor back up over.
+    n_lines: usize,
+    /// Width each cell's level is padded to, so that the grid stays aligned
+    /// once levels reach multiple digits. Only grows between frames.
+    cell_width: usize,
+    /// Total no. of flashes over all frames.
+    pub total_flashes: usize,
+    /// Step with the most flashes & its no. of flashes, the earliest on ties.
+    pub max_flashes: Option<(usize, usize)>,
+}
+impl<W: Write> Animation<W> {
+    pub fn new(out: W, delay: Duration) -> Self {
+        Self {
+            out,
+            delay,
+            n_lines: 0,
+            cell_width: 1,
+            total_flashes: 0,
+            max_flashes: None,
+        }
+    }
+
+    /// Draw the automaton's grid after the given step over the previous frame,
+    /// highlighting the given indices of cells that flashed on the step.
+    pub fn frame(
+        &mut self,
+        automaton: &CascadeAutomaton,
+        flashed: &[usize],
+        step: usize,
+    ) -> io::Result<()> {
+        let mut is_flashed = vec![false; automaton.levels.len()];
+        for &index in flashed {
+            is_flashed[index] = true;
+        }
+
+        let max_level = automaton.levels.iter().copied().max().unwrap_or(0);
+        self.cell_width = self.cell_width.max(max_level.to_string().len());
+
+        if self.n_lines > 0 {
+            // move the cursor back to the start of the previous frame
+            write!(self.out, "\x1b[{}A\r", self.n_lines)?;
+        }
+        for (y, row) in automaton.levels.chunks(automaton.width).enumerate() {
+            for (x, level) in row.iter().enumerate() {
+                let style = if is_flashed[y * automaton.width + x] {
+                    FLASH_STYLE
+                } else {
+                    IDLE_STYLE
+                };
+                write!(
+                    self.out,
+                    "{}{:>width$}{}",
+                    style,
+                    level,
+                    RESET_STYLE,
+                    width = self.cell_width
+                )?;
+            }
+            // clear any leftovers from a longer previous line
+            writeln!(self.out, "\x1b[K")?;
+        }
+        writeln!(self.out, "step {}: {} flashes\x1b[K", step, flashed.len())?;
+        self.out.flush()?;
+        self.n_lines = automaton.height + 1;
+
+        self.total_flashes += flashed.len();
+        let is_max = match self.max_flashes {
+            Some((_, max)) => flashed.len() > max,
+            None => true,
+        };
+        if is_max {
+            self.max_flashes = Some((step, flashed.len()));
+        }
+        thread::sleep(self.delay);
+        Ok(())
+    }
+}
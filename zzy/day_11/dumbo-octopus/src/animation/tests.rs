@@ -0,0 +1,52 @@
+//!
+//! Anti Helmet
+//! Advent of Code
+//! Day 11: Dumbo Octopus
+//! Unit Tests
+//!
+
+use super::*;
+use crate::automaton::Rules;
+
+fn automaton(levels: Vec<Vec<u32>>) -> CascadeAutomaton {
+    CascadeAutomaton::new(Rules::octopus(), levels).unwrap()
+}
+
+#[test]
+fn test_frame() {
+    let mut animation = Animation::new(Vec::new(), Duration::ZERO);
+    animation
+        .frame(&automaton(vec![vec![0, 3], vec![5, 0]]), &[0, 3], 1)
+        .unwrap();
+    assert_eq!(
+        String::from_utf8(animation.out.clone()).unwrap(),
+        "\x1b[1;93m0\x1b[0m\x1b[2m3\x1b[0m\x1b[K\n\
+         \x1b[2m5\x1b[0m\x1b[1;93m0\x1b[0m\x1b[K\n\
+         step 1: 2 flashes\x1b[K\n"
+    );
+
+    // later frames are drawn over the previous frame
+    animation.out.clear();
+    animation
+        .frame(&automaton(vec![vec![1, 4], vec![6, 1]]), &[], 2)
+        .unwrap();
+    assert!(String::from_utf8(animation.out.clone())
+        .unwrap()
+        .starts_with("\x1b[3A\r\x1b[2m1\x1b[0m"));
+
+    assert_eq!(animation.total_flashes, 2);
+    assert_eq!(animation.max_flashes, Some((1, 2)));
+}
+
+#[test]
+fn test_frame_multiple_digits() {
+    // levels with multiple digits are padded to keep the grid aligned
+    let mut animation = Animation::new(Vec::new(), Duration::ZERO);
+    animation
+        .frame(&automaton(vec![vec![12, 3]]), &[], 1)
+        .unwrap();
+    assert_eq!(
+        String::from_utf8(animation.out).unwrap(),
+        "\x1b[2m12\x1b[0m\x1b[2m 3\x1b[0m\x1b[K\nstep 1: 0 flashes\x1b[K\n"
+    );
+}
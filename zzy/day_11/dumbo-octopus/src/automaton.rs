@@ -0,0 +1,231 @@
+//!
+//! Anti Helmet
+//! Advent of Code
+//! Day 11: Dumbo Octopus
+//! Cascade Automaton
+//!
+
+#[cfg(test)]
+mod tests;
+
+/// Defines the offsets of the cells that neighbour a cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Neighbourhood {
+    /// 8 cells surrounding the cell, including diagonals.
+    Moore,
+    /// 4 cells orthogonally adjacent to the cell.
+    VonNeumann,
+    /// Custom (x, y) offsets relative to the cell.
+    Custom(Vec<(isize, isize)>),
+}
+impl Neighbourhood {
+    /// Parse a neighbourhood from its name: "moore", "von-neumann" or custom
+    /// offsets as semicolon separated x,y pairs ie. "-1,0;1,0".
+    /// Returns None if the neighbourhood could not be parsed.
+    pub fn parse(neighbourhood_str: &str) -> Option<Self> {
+        match neighbourhood_str {
+            "moore" => Some(Neighbourhood::Moore),
+            "von-neumann" => Some(Neighbourhood::VonNeumann),
+            _ => neighbourhood_str
+                .split(';')
+                .map(|offset_str| {
+                    let (x, y) = offset_str.split_once(',')?;
+                    Some((x.trim().parse().ok()?, y.trim().parse().ok()?))
+                })
+                .collect::<Option<_>>()
+                .map(Neighbourhood::Custom),
+        }
+    }
+
+    /// Offsets of the neighbouring cells relative to a cell as (x, y) offset pairs.
+    pub fn offsets(&self) -> Vec<(isize, isize)> {
+        match self {
+            Neighbourhood::Moore => (-1..=1)
+                .flat_map(|y| (-1..=1).map(move |x| (x, y)))
+                .filter(|&offset| offset != (0, 0))
+                .collect(),
+            Neighbourhood::VonNeumann => vec![(0, -1), (-1, 0), (1, 0), (0, 1)],
+            Neighbourhood::Custom(offsets) => offsets.clone(),
+        }
+    }
+}
+
+/// Defines how the level of a cell is reset after it fires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reset {
+    /// Set the level to the given value.
+    To(u32),
+    /// Subtract the given amount from the level, saturating at 0.
+    Subtract(u32),
+}
+impl Reset {
+    /// Parse a reset rule from "to:VALUE" or "subtract:AMOUNT".
+    /// Returns None if the reset rule could not be parsed.
+    pub fn parse(reset_str: &str) -> Option<Self> {
+        match reset_str.split_once(':')? {
+            ("to", value) => Some(Reset::To(value.parse().ok()?)),
+            ("subtract", amount) => Some(Reset::Subtract(amount.parse().ok()?)),
+            _ => None,
+        }
+    }
+}
+
+/// Defines how neighbours beyond the edges of the grid are resolved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Boundary {
+    /// Neighbours beyond the edges are dropped.
+    Clipped,
+    /// Grid wraps around its edges like a torus: neighbours beyond an edge
+    /// resolve to cells on the opposite edge.
+    Torus,
+    /// Neighbours beyond an edge are mirrored back into the grid across the edge,
+    /// so a cell on the edge may count a neighbour twice.
+    Reflecting,
+}
+impl Boundary {
+    /// Parse a boundary mode from its name: "clipped", "torus" or "reflecting".
+    /// Returns None if no boundary mode has the given name.
+    pub fn parse(boundary_str: &str) -> Option<Self> {
+        match boundary_str {
+            "clipped" => Some(Boundary::Clipped),
+            "torus" => Some(Boundary::Torus),
+            "reflecting" => Some(Boundary::Reflecting),
+            _ => None,
+        }
+    }
+
+    /// Resolve the given coordinate along an axis of the given length.
+    /// Returns None if the coordinate does not resolve to a cell on the axis.
+    fn resolve(&self, coord: isize, len: usize) -> Option<usize> {
+        let len = len as isize;
+        let coord = match self {
+            Boundary::Clipped => coord,
+            Boundary::Torus => coord.rem_euclid(len),
+            Boundary::Reflecting if coord < 0 => -coord,
+            Boundary::Reflecting if coord >= len => 2 * (len - 1) - coord,
+            Boundary::Reflecting => coord,
+        };
+        if (0..len).contains(&coord) {
+            Some(coord as usize)
+        } else {
+            None
+        }
+    }
+}
+
+/// Rules that govern how a cascade automaton steps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    /// Cells whose level is raised by a firing neighbour.
+    pub neighbourhood: Neighbourhood,
+    /// How neighbours beyond the edges of the grid are resolved.
+    pub boundary: Boundary,
+    /// Cells fire once their level exceeds the threshold.
+    pub threshold: u32,
+    /// Amount added to every cell's level at the start of each step.
+    pub increment: u32,
+    /// Amount added to each neighbour's level when a cell fires.
+    pub spread: u32,
+    /// How the level of each fired cell is reset at the end of the step.
+    pub reset: Reset,
+}
+impl Rules {
+    /// Rules of the dumbo octopuses: each step octopuses gain 1 energy and flash
+    /// above an energy level of 9, raising the energy of all 8 surrounding octopuses
+    /// by 1 & resetting to 0 after flashing.
+    pub fn octopus() -> Self {
+        Self {
+            neighbourhood: Neighbourhood::Moore,
+            boundary: Boundary::Clipped,
+            threshold: 9,
+            increment: 1,
+            spread: 1,
+            reset: Reset::To(0),
+        }
+    }
+}
+
+/// Grid of cells with energy levels where each step raises every cell's level and
+/// cells exceeding the threshold fire, raising their neighbours' levels in a cascade.
+/// Each cell fires at most once per step.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CascadeAutomaton {
+    pub rules: Rules,
+    /// Level of each cell in row major order.
+    pub levels: Vec<u32>,
+    pub width: usize,
+    pub height: usize,
+}
+impl CascadeAutomaton {
+    /// Create an automaton from the given 2D cell levels indexed (y, x).
+    /// Returns None if the rows are empty or differ in length.
+    pub fn new(rules: Rules, levels: Vec<Vec<u32>>) -> Option<Self> {
+        let width = levels.first()?.len();
+        if width == 0 || levels.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        Some(Self {
+            rules,
+            height: levels.len(),
+            levels: levels.into_iter().flatten().collect(),
+            width,
+        })
+    }
+
+    /// Indices of the cells in the neighbourhood of the cell at the given index.
+    /// Neighbours beyond the edges of the grid are resolved by the boundary mode,
+    /// excluding any that resolve back to the cell itself.
+    pub fn neighbours(&self, index: usize) -> Vec<usize> {
+        let (x, y) = ((index % self.width) as isize, (index / self.width) as isize);
+        let boundary = self.rules.boundary;
+        self.rules
+            .neighbourhood
+            .offsets()
+            .into_iter()
+            .flat_map(|(x_offset, y_offset)| {
+                let x = boundary.resolve(x + x_offset, self.width)?;
+                let y = boundary.resolve(y + y_offset, self.height)?;
+                Some(y * self.width + x)
+            })
+            .filter(|&neighbour| neighbour != index)
+            .collect()
+    }
+
+    /// Advance the automaton by a single step.
+    /// Propagates firings with a worklist of cells that have crossed the threshold,
+    /// so each firing only revisits its own neighbours.
+    /// Returns the indices of the cells that fired, in firing order.
+    pub fn step(&mut self) -> Vec<usize> {
+        let threshold = self.rules.threshold;
+        let mut fired = vec![false; self.levels.len()];
+        let mut worklist = Vec::new();
+        for (index, level) in self.levels.iter_mut().enumerate() {
+            *level += self.rules.increment;
+            if *level > threshold {
+                fired[index] = true;
+                worklist.push(index);
+            }
+        }
+
+        let mut fired_order = Vec::new();
+        while let Some(index) = worklist.pop() {
+            fired_order.push(index);
+            for neighbour in self.neighbours(index) {
+                self.levels[neighbour] += self.rules.spread;
+                if !fired[neighbour] && self.levels[neighbour] > threshold {
+                    fired[neighbour] = true;
+                    worklist.push(neighbour);
+                }
+            }
+        }
+
+        for &index in fired_order.iter() {
+            let level = &mut self.levels[index];
+            *level = match self.rules.reset {
+                Reset::To(value) => value,
+                Reset::Subtract(amount) => level.saturating_sub(amount),
+            };
+        }
+        fired_order
+    }
+}
@@ -0,0 +1,129 @@
+//!
+//! Anti Helmet
+//! Advent of Code
+//! Day 11: Dumbo Octopus
+//! Unit Tests
+//!
+
+use super::*;
+
+fn levels(rows: &[&str]) -> Vec<Vec<u32>> {
+    rows.iter()
+        .map(|row| row.chars().map(|c| c.to_digit(10).unwrap()).collect())
+        .collect()
+}
+
+#[test]
+fn test_new_rejects_ragged() {
+    assert!(CascadeAutomaton::new(Rules::octopus(), vec![]).is_none());
+    assert!(CascadeAutomaton::new(Rules::octopus(), levels(&["12", "3"])).is_none());
+}
+
+#[test]
+fn test_neighbours_clipped() {
+    let automaton = CascadeAutomaton::new(Rules::octopus(), levels(&["123", "456"])).unwrap();
+    assert_eq!(automaton.neighbours(0), vec![1, 3, 4]);
+    assert_eq!(automaton.neighbours(4), vec![0, 1, 2, 3, 5]);
+
+    let mut rules = Rules::octopus();
+    rules.neighbourhood = Neighbourhood::VonNeumann;
+    let automaton = CascadeAutomaton::new(rules, levels(&["123", "456"])).unwrap();
+    assert_eq!(automaton.neighbours(4), vec![1, 3, 5]);
+}
+
+#[test]
+fn test_neighbours_torus() {
+    let mut rules = Rules::octopus();
+    rules.boundary = Boundary::Torus;
+    let automaton = CascadeAutomaton::new(rules, levels(&["123", "456", "789"])).unwrap();
+    let mut neighbours = automaton.neighbours(0);
+    neighbours.sort_unstable();
+    assert_eq!(neighbours, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+
+    // neighbours resolving back to the cell itself are excluded
+    let automaton = CascadeAutomaton::new(automaton.rules, levels(&["12"])).unwrap();
+    assert_eq!(automaton.neighbours(0), vec![1, 1, 1, 1, 1, 1]);
+}
+
+#[test]
+fn test_neighbours_reflecting() {
+    let mut rules = Rules::octopus();
+    rules.boundary = Boundary::Reflecting;
+    rules.neighbourhood = Neighbourhood::VonNeumann;
+    let automaton = CascadeAutomaton::new(rules, levels(&["123", "456", "789"])).unwrap();
+    // corner cell counts each of its in grid neighbours twice
+    assert_eq!(automaton.neighbours(0), vec![3, 1, 1, 3]);
+    assert_eq!(automaton.neighbours(5), vec![2, 4, 4, 8]);
+}
+
+#[test]
+fn test_step_cascade() {
+    let mut automaton = CascadeAutomaton::new(
+        Rules::octopus(),
+        levels(&["11111", "19991", "19191", "19991", "11111"]),
+    )
+    .unwrap();
+    let mut fired = automaton.step();
+    assert_eq!(fired.len(), 9);
+    fired.sort_unstable();
+    assert_eq!(fired, vec![6, 7, 8, 11, 12, 13, 16, 17, 18]);
+    assert_eq!(
+        automaton.levels,
+        levels(&["34543", "40004", "50005", "40004", "34543"]).concat()
+    );
+    assert!(automaton.step().is_empty());
+}
+
+#[test]
+fn test_step_octopus_example() {
+    let mut automaton = CascadeAutomaton::new(
+        Rules::octopus(),
+        levels(&[
+            "5483143223",
+            "2745854711",
+            "5264556173",
+            "6141336146",
+            "6357385478",
+            "4167524645",
+            "2176841721",
+            "6882881134",
+            "4846848554",
+            "5283751526",
+        ]),
+    )
+    .unwrap();
+    let n_flashes: usize = (0..100).map(|_| automaton.step().len()).sum();
+    assert_eq!(n_flashes, 1656);
+}
+
+#[test]
+fn test_step_subtract_reset() {
+    let rules = Rules {
+        neighbourhood: Neighbourhood::Custom(vec![(1, 0)]),
+        threshold: 3,
+        increment: 2,
+        spread: 2,
+        reset: Reset::Subtract(4),
+        ..Rules::octopus()
+    };
+    let mut automaton = CascadeAutomaton::new(rules, levels(&["202"])).unwrap();
+    // outer cells fire, the first spreading right only to fire the middle cell,
+    // which raises the fired last cell above its reset amount
+    assert_eq!(automaton.step(), vec![2, 0, 1]);
+    assert_eq!(automaton.levels, vec![0, 0, 2]);
+}
+
+#[test]
+fn test_parse_rules() {
+    assert_eq!(Neighbourhood::parse("moore"), Some(Neighbourhood::Moore));
+    assert_eq!(
+        Neighbourhood::parse("-1,0; 1,0"),
+        Some(Neighbourhood::Custom(vec![(-1, 0), (1, 0)]))
+    );
+    assert_eq!(Neighbourhood::parse("hex"), None);
+    assert_eq!(Reset::parse("to:0"), Some(Reset::To(0)));
+    assert_eq!(Reset::parse("subtract:10"), Some(Reset::Subtract(10)));
+    assert_eq!(Reset::parse("halve"), None);
+    assert_eq!(Boundary::parse("torus"), Some(Boundary::Torus));
+    assert_eq!(Boundary::parse("klein"), None);
+}
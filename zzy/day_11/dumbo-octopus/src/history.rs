@@ -0,0 +1,120 @@
+//!
+//! Anti Helmet
+//! Advent of Code
+//! Day 11: Dumbo Octopus
+//! State History
+//!
+
+#[cfg(test)]
+mod tests;
+
+/// Describes the cycle a simulation's states eventually repeat in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// No. of steps taken before the first state of the cycle is reached.
+    pub pre_period: usize,
+    /// No. of steps before the cycle's states repeat.
+    pub length: usize,
+    /// No. of flashes that occur over each repetition of the cycle.
+    pub flashes_per_cycle: usize,
+}
+
+/// Records the states of a deterministic simulation step by step to detect when
+/// the states enter a cycle.
+///
+/// Only keeps a single state in memory using Brent's algorithm: the recorded state
+/// is compared to the state saved on the last step that was a power of two, which
+/// finds the cycle's length within a few times as many steps as the cycle ends on.
+/// The cycle's first state is then located by re-simulating with [`History::find_cycle`].
+#[derive(Debug, Default)]
+pub struct History {
+    /// State saved for comparison with subsequent states & the step it was recorded on.
+    saved: Option<(Vec<u32>, usize)>,
+    /// Total no. of flashes after each recorded step, starting with 0 before the first step.
+    total_flashes: Vec<usize>,
+    /// First step on which the simulation was synchronised, if any.
+    pub first_sync: Option<usize>,
+    /// No. of steps before the states repeat, once they have been found to cycle.
+    pub cycle_length: Option<usize>,
+    /// Cycle the states were found to repeat in, if any.
+    pub cycle: Option<Cycle>,
+}
+impl History {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// No. of steps recorded, excluding the initial state.
+    pub fn n_steps(&self) -> usize {
+        self.total_flashes.len().saturating_sub(1)
+    }
+
+    /// Record the state after the next step with the no. of flashes that occured on
+    /// the step and whether the state is synchronised. The initial state should be
+    /// recorded first with 0 flashes.
+    /// Returns the length of the cycle the states repeat in once it has been found.
+    pub fn record(&mut self, levels: &[u32], n_flashes: usize, is_sync: bool) -> Option<usize> {
+        let step = self.total_flashes.len();
+        let total_flashes = self.total_flashes.last().copied().unwrap_or(0) + n_flashes;
+        self.total_flashes.push(total_flashes);
+        if is_sync && self.first_sync.is_none() {
+            self.first_sync = Some(step);
+        }
+        if self.cycle_length.is_none() {
+            match self.saved.as_mut() {
+                Some((saved, saved_step)) if saved.as_slice() == levels => {
+                    self.cycle_length = Some(step - *saved_step);
+                }
+                Some((saved, saved_step)) if step.is_power_of_two() => {
+                    saved.clear();
+                    saved.extend_from_slice(levels);
+                    *saved_step = step;
+                }
+                Some(_) => (),
+                None => self.saved = Some((levels.to_vec(), step)),
+            }
+        }
+        self.cycle_length
+    }
+
+    /// Find the cycle the recorded states repeat in by re-simulating them from the
+    /// given initial state, advancing the state a step at a time with the given step.
+    /// Returns None if the length of the cycle has not been found yet.
+    pub fn find_cycle<S, F>(&mut self, initial: S, mut step: F) -> Option<Cycle>
+    where
+        S: Clone + PartialEq,
+        F: FnMut(&mut S),
+    {
+        let length = self.cycle_length?;
+        // the first state of the cycle is the first state repeated length steps later
+        let mut state = initial.clone();
+        let mut ahead = initial;
+        for _ in 0..length {
+            step(&mut ahead);
+        }
+        let mut begin = 0;
+        while state != ahead {
+            step(&mut state);
+            step(&mut ahead);
+            begin += 1;
+        }
+        self.cycle = Some(Cycle {
+            pre_period: begin,
+            length,
+            flashes_per_cycle: self.total_flashes[begin + length] - self.total_flashes[begin],
+        });
+        self.cycle
+    }
+
+    /// Check whether the simulation ever synchronises.
+    /// Returns None if the states have not been recorded long enough to tell:
+    /// synchronisation has not occured and the states have not yet entered a cycle.
+    pub fn sync_reachable(&self) -> Option<bool> {
+        match (self.first_sync, self.cycle_length) {
+            (Some(_), _) => Some(true),
+            // every state reachable has been seen once the states are found to cycle
+            (None, Some(_)) => Some(false),
+            (None, None) => None,
+        }
+    }
+}
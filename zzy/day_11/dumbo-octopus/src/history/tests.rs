@@ -0,0 +1,61 @@
+//!
+//! Anti Helmet
+//! Advent of Code
+//! Day 11: Dumbo Octopus
+//! Unit Tests
+//!
+
+use super::*;
+
+/// Step the given state of a simulation entering a cycle of 1, 2, 3 after state 0.
+fn step(state: &mut u32) {
+    *state = if *state == 3 { 1 } else { *state + 1 };
+}
+
+#[test]
+fn test_record_cycle() {
+    let mut history = History::new();
+    let mut state = 0;
+    let mut lengths = Vec::new();
+    for _ in 0..8 {
+        lengths.push(history.record(&[state], state as usize, false));
+        if history.n_steps() == 3 {
+            assert_eq!(history.sync_reachable(), None);
+        }
+        step(&mut state);
+    }
+    // state 1 saved on step 4 repeats on step 7
+    assert_eq!(lengths[..7], [None; 7]);
+    assert_eq!(lengths[7], Some(3));
+    assert_eq!(history.sync_reachable(), Some(false));
+
+    let cycle = Cycle {
+        pre_period: 1,
+        length: 3,
+        flashes_per_cycle: 1 + 2 + 3,
+    };
+    assert_eq!(history.find_cycle(0, step), Some(cycle));
+    assert_eq!(history.cycle, Some(cycle));
+
+    // cycle length is kept once found
+    assert_eq!(history.record(&[2], 2, false), Some(3));
+}
+
+#[test]
+fn test_find_cycle_before_length() {
+    let mut history = History::new();
+    history.record(&[0], 0, false);
+    assert_eq!(history.find_cycle(0, step), None);
+    assert_eq!(history.cycle, None);
+}
+
+#[test]
+fn test_record_sync() {
+    let mut history = History::new();
+    history.record(&[1, 2], 0, false);
+    history.record(&[0, 0], 2, true);
+    assert_eq!(history.first_sync, Some(1));
+    assert_eq!(history.sync_reachable(), Some(true));
+    assert_eq!(history.record(&[0, 0], 2, true), Some(1));
+    assert_eq!(history.first_sync, Some(1));
+}
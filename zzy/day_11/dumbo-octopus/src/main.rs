@@ -4,153 +4,56 @@
 //! Day 11: Dumbo Octopus
 //!
 
-use std::collections::HashSet;
+use std::env;
 use std::fmt::{Display, Formatter, Result};
-use std::io::{stdin, BufRead};
+use std::io::{stdin, stdout, BufRead};
+use std::time::Duration;
 
-/// Represents a point on a 2D plane.
-#[derive(Copy, Clone, PartialEq, Eq, Hash)]
-struct Point(usize, usize);
+mod animation;
+mod automaton;
+mod history;
+use animation::Animation;
+use automaton::{Boundary, CascadeAutomaton, Neighbourhood, Reset, Rules};
+use history::History;
 
 /// Represents the energy states of all octopuses at a point in time.
 struct OctopusState {
-    /// Energy level of each octopus indexed (y, x)
-    energy_levels: Vec<Vec<u8>>,
+    /// Cascade automaton modelling the energy level of each octopus.
+    automaton: CascadeAutomaton,
 }
 impl OctopusState {
-    fn new(energy_levels: Vec<Vec<u8>>) -> OctopusState {
-        if energy_levels.len() <= 0 {
-            panic!("Expected to be given 2D vector of energy levels, given only 1D.")
+    fn new(rules: Rules, energy_levels: Vec<Vec<u8>>) -> OctopusState {
+        let energy_levels = energy_levels
+            .into_iter()
+            .map(|row| row.into_iter().map(u32::from).collect())
+            .collect();
+        OctopusState {
+            automaton: CascadeAutomaton::new(rules, energy_levels)
+                .expect("Expected a non empty 2D grid of energy levels with equal length rows"),
         }
-        OctopusState { energy_levels }
-    }
-
-    /// Retrieves the energy level of the octopus at the given point
-    fn at(&self, Point(x, y): Point) -> u8 {
-        self.energy_levels[y][x]
-    }
-
-    /// Length of the y axis of the stored energy levels
-    fn y_len(&self) -> usize {
-        self.energy_levels.len()
-    }
-
-    /// Length of the x axis of the stored energy levels
-    fn x_len(&self) -> usize {
-        self.energy_levels[0].len()
-    }
-
-    /// Derive the defined points surrounding the given points
-    /// Surrounding points are 1 euclidean distance away from the given point.
-    /// Defined points are within the bounds of the dimensions of the stored energy levels.
-    /// Returns a vector of defined, surrounding points relative to given point.
-    fn surrounding(&self, Point(x, y): Point) -> Vec<Point> {
-        // generate offsets for surrounding point
-        let offset: [isize; 3] = [-1, 0, 1];
-        offset
-            .iter()
-            .flat_map(|&x_offset| {
-                offset
-                    .iter()
-                    .flat_map(|&y_offset| {
-                        let (new_x, x_overflow) = isize::overflowing_add(x as isize, x_offset);
-                        let (new_y, y_overflow) = isize::overflowing_add(y as isize, y_offset);
-
-                        // check generated points are within bounds
-                        if x_overflow || y_overflow {
-                            None
-                        } else {
-                            let (new_x, new_y) = (new_x as usize, new_y as usize);
-
-                            if new_x >= self.x_len() || new_y >= self.y_len() {
-                                None
-                            }
-                            // identical to given point, exclude.
-                            else if new_x == x && new_y == y {
-                                None
-                            } else {
-                                Some(Point(new_x, new_y))
-                            }
-                        }
-                    })
-                    .collect::<Vec<Point>>()
-            })
-            .collect()
-    }
-
-    /// Compiles the points of the octopuses that are elligible for flashing
-    fn flashing_pts(&self) -> HashSet<Point> {
-        (0..self.y_len())
-            .flat_map(|y| (0..self.x_len()).map(move |x| Point(x, y)))
-            .filter(|&pt| self.at(pt) > 9)
-            .collect()
     }
 
     /// Simulate a single octopuses' flashing step to derive the next state
-    /// Returns the next octopuses state and the no. of flashes that occured.
-    fn next(mut self) -> (OctopusState, u32) {
-        // before resolving any flashes increament energy levels by 1
-        for y in 0..self.y_len() {
-            for x in 0..self.x_len() {
-                self.energy_levels[y][x] += 1;
-            }
-        }
-
-        // resolve all flashing octopuses,
-        let mut flashed_pts = HashSet::new();
-        loop {
-            let flashing_pts: HashSet<_> = self
-                .flashing_pts()
-                .difference(&flashed_pts)
-                .map(|&pt| pt)
-                .collect();
-            if flashing_pts.len() == 0 {
-                break;
-            }
-
-            // propagating energy increases to surrounding octopuses
-            for &flashed_pt in &flashing_pts {
-                for pt in self.surrounding(flashed_pt) {
-                    if !flashed_pts.contains(&pt) {
-                        let Point(x, y) = pt;
-                        self.energy_levels[y][x] += 1;
-                    }
-                }
-            }
-
-            // mark flashing octopuses as flashed.
-            flashed_pts = flashed_pts.union(&flashing_pts).map(|&pt| pt).collect();
-        }
-
-        // reset the flashed octopuses' stored energy levels
-        for &Point(x, y) in &flashed_pts {
-            self.energy_levels[y][x] = 0;
-        }
-
-        (self, flashed_pts.len() as u32)
+    /// Returns the next octopuses state and the indices of the octopuses that flashed.
+    fn next(mut self) -> (OctopusState, Vec<usize>) {
+        let flashed = self.automaton.step();
+        (self, flashed)
     }
 
     /// Check if all octopuses are synchronized in their energy levels
     fn sync(&self) -> bool {
-        if self.x_len() * self.y_len() <= 1 {
-            // no elements to compare to, in sync by default.
-            true
-        } else {
-            let target = self.energy_levels[0][0];
-            self.energy_levels
-                .iter()
-                .flat_map(|col| col.iter())
-                .all(|&level| level == target)
-        }
+        let levels = &self.automaton.levels;
+        levels.iter().all(|&level| level == levels[0])
     }
 }
 impl Display for OctopusState {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         // dump contents of octopus energy levels
         let lines: Vec<String> = self
-            .energy_levels
-            .iter()
-            .map(|col| col.iter().map(|c| format!("{}", c)).collect::<String>())
+            .automaton
+            .levels
+            .chunks(self.automaton.width)
+            .map(|row| row.iter().map(|c| format!("{}", c)).collect::<String>())
             .collect();
 
         write!(f, "{}", lines.as_slice().join("\n"))
@@ -158,8 +61,59 @@ impl Display for OctopusState {
 }
 
 fn main() {
+    // parse options from command line arguments, defaulting to the octopus rules:
+    // each step octopuses gain 1 energy, flash above the threshold, raising the
+    // energy of all 8 surrounding octopuses by 1 & resetting to 0 after flashing.
+    // --neighbourhood=moore|von-neumann|X,Y;X,Y.. cells raised by a flashing octopus.
+    // --boundary=clipped|torus|reflecting how neighbours beyond the grid's edges
+    //   are resolved (default: clipped).
+    // --threshold=N energy level above which octopuses flash.
+    // --increment=N energy gained by every octopus each step.
+    // --spread=N energy gained from each flashing neighbour.
+    // --reset=to:N|subtract:N how energy levels are reset after flashing.
+    // --max-steps=N no. of steps after which to stop simulating (default: 100000).
+    // --animate to redraw the octopuses' energy levels in the terminal each step,
+    //   highlighting the octopuses that flashed.
+    // --delay=MS delay between each animation frame in milliseconds (default: 100).
+    let mut rules = Rules::octopus();
+    let (mut max_steps, mut animate, mut delay_ms) = (100_000, false, 100);
+    for arg in env::args().skip(1) {
+        match arg.split_once('=').unwrap_or((&arg, "")) {
+            ("--neighbourhood", value) => {
+                rules.neighbourhood = Neighbourhood::parse(value)
+                    .unwrap_or_else(|| panic!("Unsupported neighbourhood: {}", value))
+            }
+            ("--boundary", value) => {
+                rules.boundary = Boundary::parse(value)
+                    .unwrap_or_else(|| panic!("Unsupported boundary mode: {}", value))
+            }
+            ("--threshold", value) => {
+                rules.threshold = value.parse().expect("Expected threshold to be an integer")
+            }
+            ("--increment", value) => {
+                rules.increment = value.parse().expect("Expected increment to be an integer")
+            }
+            ("--spread", value) => {
+                rules.spread = value.parse().expect("Expected spread to be an integer")
+            }
+            ("--reset", value) => {
+                rules.reset =
+                    Reset::parse(value).unwrap_or_else(|| panic!("Unsupported reset: {}", value))
+            }
+            ("--max-steps", value) => {
+                max_steps = value.parse().expect("Expected max steps to be an integer")
+            }
+            ("--animate", _) => animate = true,
+            ("--delay", value) => {
+                delay_ms = value.parse().expect("Expected delay to be an integer")
+            }
+            _ => panic!("Unsupported option: {}", arg),
+        }
+    }
+
     // read initial state of octopus energy levels from stdin
     let initial_state = OctopusState::new(
+        rules,
         stdin()
             .lock()
             .lines()
@@ -176,14 +130,59 @@ fn main() {
             .collect(),
     );
 
-    // simulate octopus flashing until octopuses' energy levels will be in sync
+    // simulate octopus flashing until the energy levels enter a cycle, recording
+    // when the octopuses' energy levels will be in sync
+    let initial_automaton = initial_state.automaton.clone();
     let mut state = initial_state;
-    let mut n_steps = 0;
-    while !state.sync() {
-        let (next_state, _) = state.next();
+    let mut history = History::new();
+    let mut animation = if animate {
+        Some(Animation::new(stdout(), Duration::from_millis(delay_ms)))
+    } else {
+        None
+    };
+    let mut flashed = Vec::new();
+    loop {
+        history.record(&state.automaton.levels, flashed.len(), state.sync());
+        if let Some(animation) = animation.as_mut() {
+            animation
+                .frame(&state.automaton, &flashed, history.n_steps())
+                .expect("Failed to draw animation frame to stdout");
+        }
+        if history.cycle_length.is_some() || history.n_steps() >= max_steps {
+            break;
+        }
+        let (next_state, next_flashed) = state.next();
         state = next_state;
-        n_steps += 1;
+        flashed = next_flashed;
+    }
+    // locate the start of the cycle by re-simulating from the initial energy levels
+    history.find_cycle(initial_automaton, |automaton| {
+        automaton.step();
+    });
+    if let Some(animation) = animation {
+        print!(
+            "Animated {} steps with {} flashes",
+            history.n_steps(),
+            animation.total_flashes
+        );
+        match animation.max_flashes {
+            Some((step, n_flashes)) if n_flashes > 0 => {
+                println!(", most flashes ({}) on step {}", n_flashes, step)
+            }
+            _ => println!(),
+        }
     }
 
-    println!("Octopuses will be sync on step {}", n_steps);
+    match (history.first_sync, history.sync_reachable()) {
+        (Some(n_steps), _) => println!("Octopuses will be sync on step {}", n_steps),
+        (None, Some(false)) => println!("Octopuses will never be in sync"),
+        _ => println!("Octopuses did not sync within {} steps", history.n_steps()),
+    }
+    match history.cycle {
+        Some(cycle) => println!(
+            "Energy levels cycle after {} steps, repeating every {} steps with {} flashes per cycle",
+            cycle.pre_period, cycle.length, cycle.flashes_per_cycle
+        ),
+        None => println!("No cycle found within {} steps", history.n_steps()),
+    }
 }
@@ -0,0 +1,196 @@
+//!
+//! Anti Helmet
+//! Advent of Code
+//! Day 12: Passage Pathing
+//! Path Counting
+//!
+
+use crate::policy::VisitPolicy;
+use crate::{Cave, CaveSystem};
+use std::collections::{HashMap, HashSet};
+
+#[cfg(test)]
+mod tests;
+
+/// Counts the paths between two caves in a cave system without enumerating them.
+///
+/// The caves visited so far only matter to the rest of a path through how many times
+/// each small cave has been visited, so the no. of paths is memoised on the current cave
+/// & the visit counts of small caves, packed into a bitmask with a fixed width field
+/// per small cave.
+pub struct PathCounter<'a> {
+    system: &'a CaveSystem,
+    policy: &'a VisitPolicy,
+    end: u8,
+    /// Offset of each small cave's visit count field in the packed visit counts,
+    /// indexed by cave id.
+    small_offsets: Vec<Option<u32>>,
+    /// Mask of the bits in a single visit count field.
+    field_mask: u128,
+    /// No. of paths to the end cave memoised by cave id & packed visit counts.
+    memo: HashMap<(u8, u128), u64>,
+}
+impl<'a> PathCounter<'a> {
+    /// Create a counter for paths that end at the given cave, visiting small caves
+    /// as allowed by the given visit policy.
+    /// Panics if the cave system has too many small caves to pack their visit counts
+    /// into the bitmask.
+    pub fn new(system: &'a CaveSystem, policy: &'a VisitPolicy, end: &Cave) -> Self {
+        let n_caves = system.id_cave_map.len();
+
+        // size visit count fields to fit the largest no. of visits to any small cave
+        let small_names: Vec<_> = (0..n_caves as u8)
+            .map(|id| match &system.id_cave_map[&id] {
+                Cave::Small(name) => Some(name.as_str()),
+                _ => None,
+            })
+            .collect();
+        let max_limit = small_names
+            .iter()
+            .flatten()
+            .map(|name| policy.limit(name))
+            .max()
+            .unwrap_or(0);
+        let field_width = u8::BITS - max_limit.leading_zeros();
+        let n_small = small_names.iter().flatten().count() as u32;
+        if n_small * field_width > u128::BITS {
+            panic!(
+                "Expected visit counts of {} small caves to fit in {} bits, requires {} bits",
+                n_small,
+                u128::BITS,
+                n_small * field_width
+            );
+        }
+        let mut n_offsets = 0;
+        let small_offsets = small_names
+            .iter()
+            .map(|name| {
+                name.map(|_| {
+                    n_offsets += 1;
+                    (n_offsets - 1) * field_width
+                })
+            })
+            .collect();
+
+        Self {
+            system,
+            policy,
+            end: system.cave_id_map[end],
+            small_offsets,
+            field_mask: (1 << field_width) - 1,
+            memo: HashMap::new(),
+        }
+    }
+
+    /// Count the paths from the given cave to the end cave.
+    pub fn count(&mut self, begin: &Cave) -> u64 {
+        let begin_id = self.system.cave_id_map[begin];
+        let visits = self.small_offsets[begin_id as usize].map_or(0, |offset| 1 << offset);
+        self.count_from(begin_id, visits, 0)
+    }
+
+    /// Count the paths from the given cave to the end cave, given the packed visit
+    /// counts of small caves & the no. of small caves visited more than once.
+    fn count_from(&mut self, cave_id: u8, visits: u128, n_repeated: usize) -> u64 {
+        if cave_id == self.end {
+            // base case: found target end cave
+            return 1;
+        }
+        if let Some(&n_paths) = self.memo.get(&(cave_id, visits)) {
+            return n_paths;
+        }
+
+        let n_paths = self
+            .next_states(cave_id, visits, n_repeated)
+            .into_iter()
+            .map(|(next_id, visits, n_repeated)| self.count_from(next_id, visits, n_repeated))
+            .sum();
+        self.memo.insert((cave_id, visits), n_paths);
+        n_paths
+    }
+
+    /// Find the states that can be reached in a single move from the given cave,
+    /// given the packed visit counts of small caves & the no. of small caves visited
+    /// more than once. Each state is given as the cave moved to, the packed visit counts
+    /// & the no. of small caves visited more than once after the move.
+    fn next_states(&self, cave_id: u8, visits: u128, n_repeated: usize) -> Vec<(u8, u128, usize)> {
+        (0..self.system.adjacency_matrix.len())
+            .filter(|&next_id| self.system.adjacency_matrix[cave_id as usize][next_id])
+            .flat_map(|next_id| {
+                match (
+                    &self.system.id_cave_map[&(next_id as u8)],
+                    self.small_offsets[next_id],
+                ) {
+                    // start cave cannot be revisited
+                    (Cave::Start, _) => None,
+                    // small caves can be visited as allowed by the visit policy
+                    (Cave::Small(name), Some(offset)) => {
+                        let n_visits = ((visits >> offset) & self.field_mask) as u8;
+                        if self.policy.can_visit(name, n_visits, n_repeated) {
+                            let n_repeated = n_repeated + (n_visits == 1) as usize;
+                            Some((next_id as u8, visits + (1 << offset), n_repeated))
+                        } else {
+                            None
+                        }
+                    }
+                    // big caves can be visited any number of times
+                    _ => Some((next_id as u8, visits, n_repeated)),
+                }
+            })
+            .collect()
+    }
+
+    /// Count the no. of times paths from the given cave to the end cave traverse each
+    /// connection between caves, keyed by the ids of the connected caves in ascending order.
+    ///
+    /// Sums the paths through each move between states: the no. of paths from the given
+    /// cave that reach the move's source state times the no. of paths from its destination
+    /// state to the end cave. Paths reaching each state are counted in topological order
+    /// of the states, which never repeat on a path as big caves are not connected.
+    pub fn edge_counts(&mut self, begin: &Cave) -> HashMap<(u8, u8), u64> {
+        let begin_id = self.system.cave_id_map[begin];
+        let visits = self.small_offsets[begin_id as usize].map_or(0, |offset| 1 << offset);
+        let begin_state = (begin_id, visits, 0);
+
+        // order states reachable from the begin cave by depth first post order,
+        // which reversed gives a topological order
+        let mut seen = HashSet::new();
+        seen.insert((begin_id, visits));
+        let mut post_order = Vec::new();
+        let mut stack = vec![(begin_state, self.next_states(begin_id, visits, 0))];
+        while let Some((state, next_states)) = stack.last_mut() {
+            let state = *state;
+            match next_states.pop() {
+                Some(next @ (next_id, next_visits, next_repeated)) => {
+                    if next_id != self.end && seen.insert((next_id, next_visits)) {
+                        let next_states = self.next_states(next_id, next_visits, next_repeated);
+                        stack.push((next, next_states));
+                    }
+                }
+                None => {
+                    post_order.push(state);
+                    stack.pop();
+                }
+            }
+        }
+
+        // propagate the no. of paths reaching each state in topological order
+        let mut n_reaching = HashMap::new();
+        n_reaching.insert((begin_id, visits), 1);
+        let mut edge_counts = HashMap::new();
+        for &(cave_id, visits, n_repeated) in post_order.iter().rev() {
+            let n_paths_in = n_reaching[&(cave_id, visits)];
+            for (next_id, next_visits, next_repeated) in
+                self.next_states(cave_id, visits, n_repeated)
+            {
+                let n_paths_out = self.count_from(next_id, next_visits, next_repeated);
+                let edge = (cave_id.min(next_id), cave_id.max(next_id));
+                *edge_counts.entry(edge).or_insert(0) += n_paths_in * n_paths_out;
+                if next_id != self.end {
+                    *n_reaching.entry((next_id, next_visits)).or_insert(0) += n_paths_in;
+                }
+            }
+        }
+        edge_counts
+    }
+}
@@ -0,0 +1,118 @@
+//!
+//! Anti Helmet
+//! Advent of Code
+//! Day 12: Passage Pathing
+//! Unit Tests
+//!
+
+use super::*;
+use crate::fixtures::system;
+use crate::paths::Paths;
+
+fn examples() -> Vec<(CaveSystem, u64)> {
+    vec![
+        (
+            system(&["start-A", "start-b", "A-c", "A-b", "b-d", "A-end", "b-end"]),
+            36,
+        ),
+        (
+            system(&[
+                "dc-end", "HN-start", "start-kj", "dc-start", "dc-HN", "LN-dc", "HN-end", "kj-sa",
+                "kj-HN", "kj-dc",
+            ]),
+            103,
+        ),
+        (
+            system(&[
+                "fs-end", "he-DX", "fs-he", "start-DX", "pj-DX", "end-zg", "zg-sl", "zg-pj",
+                "pj-he", "RW-he", "fs-DX", "pj-RW", "zg-RW", "start-pj", "he-WI", "zg-he", "pj-fs",
+                "start-RW",
+            ]),
+            3509,
+        ),
+    ]
+}
+
+#[test]
+fn test_count_examples() {
+    for (system, n_paths) in examples() {
+        let mut counter = PathCounter::new(&system, &VisitPolicy::OneSmallTwice, &Cave::End);
+        assert_eq!(counter.count(&Cave::Start), n_paths);
+        let n_enumerated = Paths::new(
+            &system,
+            &VisitPolicy::OneSmallTwice,
+            &Cave::Start,
+            &Cave::End,
+        )
+        .count();
+        assert_eq!(n_enumerated as u64, n_paths);
+    }
+}
+
+#[test]
+fn test_count_policies() {
+    let policies = [
+        VisitPolicy::SmallOnce,
+        VisitPolicy::OneSmallTwice,
+        VisitPolicy::SmallTwice(0),
+        VisitPolicy::SmallTwice(2),
+        VisitPolicy::parse("per-cave:*=2").unwrap(),
+        VisitPolicy::parse("per-cave:dc=3,kj=2").unwrap(),
+    ];
+    // skip the largest example, which has too many paths to enumerate quickly
+    for (system, _) in examples().into_iter().take(2) {
+        for policy in policies.iter() {
+            let n_paths = PathCounter::new(&system, policy, &Cave::End).count(&Cave::Start);
+            let n_enumerated = Paths::new(&system, policy, &Cave::Start, &Cave::End).count();
+            assert_eq!(n_paths, n_enumerated as u64, "Mismatch for {:?}", policy);
+        }
+    }
+    let (system, _) = examples().remove(0);
+    let n_paths =
+        PathCounter::new(&system, &VisitPolicy::SmallOnce, &Cave::End).count(&Cave::Start);
+    assert_eq!(n_paths, 10);
+}
+
+#[test]
+fn test_edge_counts() {
+    for (system, _) in examples().into_iter().take(2) {
+        for policy in [VisitPolicy::SmallOnce, VisitPolicy::OneSmallTwice].iter() {
+            // count traversals of each connection by enumerating paths
+            let mut expected = HashMap::new();
+            for path in Paths::new(&system, policy, &Cave::Start, &Cave::End) {
+                for pair in path.windows(2) {
+                    let (src_id, dest_id) =
+                        (system.cave_id_map[pair[0]], system.cave_id_map[pair[1]]);
+                    *expected
+                        .entry((src_id.min(dest_id), src_id.max(dest_id)))
+                        .or_insert(0) += 1;
+                }
+            }
+            let mut counter = PathCounter::new(&system, policy, &Cave::End);
+            let mut edge_counts = counter.edge_counts(&Cave::Start);
+            edge_counts.retain(|_, count| *count > 0);
+            assert_eq!(edge_counts, expected, "Mismatch for {:?}", policy);
+        }
+    }
+}
+
+#[test]
+fn test_count_many_small_caves() {
+    // chain of small caves, each linked to the next directly & through a big cave
+    let names: Vec<_> = (0..40).map(|i| format!("s{}", i)).collect();
+    let mut connections = vec![
+        (Cave::Start, Cave::parse("s0")),
+        (Cave::parse("s39"), Cave::End),
+    ];
+    for (i, (src, dest)) in names.iter().zip(names.iter().skip(1)).enumerate() {
+        let big = format!("B{}", i);
+        connections.push((Cave::parse(src), Cave::parse(dest)));
+        connections.push((Cave::parse(src), Cave::parse(&big)));
+        connections.push((Cave::parse(&big), Cave::parse(dest)));
+    }
+    let system = CaveSystem::new(connections).unwrap();
+    let n_paths =
+        PathCounter::new(&system, &VisitPolicy::OneSmallTwice, &Cave::End).count(&Cave::Start);
+    // each of the 39 links can be crossed directly or through its big cave
+    assert!(n_paths > 1 << 39);
+}
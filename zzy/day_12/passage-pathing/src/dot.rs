@@ -0,0 +1,64 @@
+//!
+//! Anti Helmet
+//! Advent of Code
+//! Day 12: Passage Pathing
+//! Graphviz DOT Export
+//!
+
+use crate::{Cave, CaveSystem};
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+#[cfg(test)]
+mod tests;
+
+/// Node shape used to draw the given cave.
+fn shape(cave: &Cave) -> &'static str {
+    match cave {
+        Cave::Start => "invhouse",
+        Cave::End => "house",
+        Cave::Big(_) => "box",
+        Cave::Small(_) => "ellipse",
+    }
+}
+
+/// Write the given cave system as an undirected graph in Graphviz DOT format,
+/// with node shapes distinguishing start, end, big & small caves.
+/// If edge counts keyed by the ids of connected caves in ascending order are given,
+/// each connection is labelled with its count & drawn thicker for larger counts.
+pub fn write_dot<W: Write>(
+    out: &mut W,
+    system: &CaveSystem,
+    edge_counts: Option<&HashMap<(u8, u8), u64>>,
+) -> io::Result<()> {
+    writeln!(out, "graph caves {{")?;
+    let n_caves = system.id_cave_map.len() as u8;
+    for id in 0..n_caves {
+        let cave = &system.id_cave_map[&id];
+        writeln!(
+            out,
+            "    c{} [label=\"{}\", shape={}];",
+            id,
+            cave,
+            shape(cave)
+        )?;
+    }
+
+    let max_count = edge_counts.and_then(|counts| counts.values().copied().max());
+    for src_id in 0..n_caves {
+        for dest_id in src_id + 1..n_caves {
+            if !system.adjacency_matrix[src_id as usize][dest_id as usize] {
+                continue;
+            }
+            write!(out, "    c{} -- c{}", src_id, dest_id)?;
+            if let Some(counts) = edge_counts {
+                let count = counts.get(&(src_id, dest_id)).copied().unwrap_or(0);
+                // scale line widths from 1 to 5 relative to the largest count
+                let pen_width = 1.0 + 4.0 * count as f64 / max_count.unwrap_or(0).max(1) as f64;
+                write!(out, " [label=\"{}\", penwidth={:.2}]", count, pen_width)?;
+            }
+            writeln!(out, ";")?;
+        }
+    }
+    writeln!(out, "}}")
+}
@@ -0,0 +1,46 @@
+//!
+//! Anti Helmet
+//! Advent of Code
+//! Day 12: Passage Pathing
+//! Unit Tests
+//!
+
+use super::*;
+use crate::fixtures::system;
+
+#[test]
+fn test_write_dot() {
+    let mut out = Vec::new();
+    write_dot(&mut out, &system(&["start-A", "A-b", "A-end"]), None).unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "graph caves {
+    c0 [label=\"start\", shape=invhouse];
+    c1 [label=\"A\", shape=box];
+    c2 [label=\"b\", shape=ellipse];
+    c3 [label=\"end\", shape=house];
+    c0 -- c1;
+    c1 -- c2;
+    c1 -- c3;
+}
+"
+    );
+}
+
+#[test]
+fn test_write_dot_edge_counts() {
+    let edge_counts = [((0, 1), 2), ((1, 2), 2), ((1, 3), 4)]
+        .iter()
+        .copied()
+        .collect();
+    let mut out = Vec::new();
+    write_dot(
+        &mut out,
+        &system(&["start-A", "A-b", "A-end"]),
+        Some(&edge_counts),
+    )
+    .unwrap();
+    let dot = String::from_utf8(out).unwrap();
+    assert!(dot.contains("c0 -- c1 [label=\"2\", penwidth=3.00];"));
+    assert!(dot.contains("c1 -- c3 [label=\"4\", penwidth=5.00];"));
+}
@@ -0,0 +1,13 @@
+//!
+//! Anti Helmet
+//! Advent of Code
+//! Day 12: Passage Pathing
+//! Test Fixtures
+//!
+
+use crate::CaveSystem;
+
+/// Create a cave system from the given connections in the input format: SRC-DEST.
+pub fn system(connections: &[&str]) -> CaveSystem {
+    CaveSystem::parse(connections.iter()).unwrap()
+}
@@ -4,18 +4,55 @@
 //! Day 12: Passage Pathing
 //!
 
-use std::collections::{HashMap, HashSet};
-use std::io::{stdin, BufRead};
+use std::collections::HashMap;
+use std::env;
+use std::fmt::{self, Display, Formatter};
+use std::fs::File;
+use std::io::{stdin, BufRead, BufWriter};
+
+mod count;
+mod dot;
+#[cfg(test)]
+mod fixtures;
+mod paths;
+mod policy;
+use count::PathCounter;
+use dot::write_dot;
+use paths::Paths;
+use policy::VisitPolicy;
 
 /// Represents a cave in a cave system
-#[derive(PartialEq, Eq, Hash, Clone)]
-enum Cave {
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum Cave {
     Start,
     End,
     Small(String),
     Big(String),
 }
 
+impl Cave {
+    /// Parse the cave with the given name, typed by its name: caves named in lowercase
+    /// are small, other than the start & end caves, while the rest are big.
+    fn parse(name: &str) -> Cave {
+        match name {
+            "start" => Cave::Start,
+            "end" => Cave::End,
+            _ if name == name.to_lowercase() => Cave::Small(name.to_string()),
+            _ => Cave::Big(name.to_string()),
+        }
+    }
+}
+
+impl Display for Cave {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Cave::Start => write!(f, "start"),
+            Cave::End => write!(f, "end"),
+            Cave::Small(name) | Cave::Big(name) => write!(f, "{}", name),
+        }
+    }
+}
+
 /// Represents a traversable cave system
 struct CaveSystem {
     adjacency_matrix: Vec<Vec<bool>>,
@@ -25,16 +62,26 @@ struct CaveSystem {
 
 impl CaveSystem {
     /// Create a new cave system formed from the given list of connections between caves.
-    fn new(connections: Vec<(Cave, Cave)>) -> CaveSystem {
-        // build mapping between auto assigned cave id and cave instance
-        let cave_id_map: HashMap<Cave, u8> = connections
+    /// Returns an error if two big caves are connected, as paths could then move back
+    /// & forth between them infinitely many times.
+    fn new(connections: Vec<(Cave, Cave)>) -> Result<CaveSystem, String> {
+        if let Some((src, dest)) = connections
             .iter()
-            .flat_map(|(src, dest)| vec![src, dest])
-            .collect::<HashSet<_>>()
-            .into_iter()
-            .enumerate()
-            .map(|(i, cave)| (cave.clone(), i as u8))
-            .collect();
+            .find(|connection| matches!(connection, (Cave::Big(_), Cave::Big(_))))
+        {
+            return Err(format!(
+                "Expected no connections between big caves, which allow infinitely many paths: {}-{}",
+                src, dest
+            ));
+        }
+
+        // build mapping between auto assigned cave id and cave instance,
+        // assigning ids in order of first appearance
+        let mut cave_id_map: HashMap<Cave, u8> = HashMap::new();
+        for cave in connections.iter().flat_map(|(src, dest)| vec![src, dest]) {
+            let next_id = cave_id_map.len() as u8;
+            cave_id_map.entry(cave.clone()).or_insert(next_id);
+        }
 
         // build mapping between cave instance and auto assigned cave id
         let id_cave_map: HashMap<u8, Cave> = cave_id_map
@@ -46,8 +93,8 @@ impl CaveSystem {
         let n_caves = cave_id_map.len();
         let mut adjacency_matrix = vec![vec![false; n_caves]; n_caves];
         for (src, dest) in connections.iter() {
-            let src_id = cave_id_map[&src] as usize;
-            let dest_id = cave_id_map[&dest] as usize;
+            let src_id = cave_id_map[src] as usize;
+            let dest_id = cave_id_map[dest] as usize;
 
             // mark source and destination caves as adjacency to each other
             // since the connections are undirected,
@@ -56,98 +103,111 @@ impl CaveSystem {
             adjacency_matrix[dest_id][src_id] = true;
         }
 
-        CaveSystem {
-            cave_id_map: cave_id_map,
-            id_cave_map: id_cave_map,
-            adjacency_matrix: adjacency_matrix,
-        }
+        Ok(CaveSystem {
+            cave_id_map,
+            id_cave_map,
+            adjacency_matrix,
+        })
     }
 
-    /// Returns to the caves that are connected to the given cave in this cavef\
-    /// system.
-    fn connected(&self, cave: &Cave) -> Vec<&Cave> {
-        let cave_id = self.cave_id_map[cave];
+    /// Parse a cave system from the given lines of connections in the format: SRC-DEST.
+    /// Returns an error if a connection is malformed or connects two big caves.
+    fn parse<S: AsRef<str>>(lines: impl Iterator<Item = S>) -> Result<CaveSystem, String> {
+        let connections = lines
+            .map(|line| {
+                if let [src, dest] = line.as_ref().split('-').collect::<Vec<&str>>().as_slice() {
+                    Ok((Cave::parse(src), Cave::parse(dest)))
+                } else {
+                    Err("Expected connections to be in the format: SRC-DEST".to_string())
+                }
+            })
+            .collect::<Result<_, _>>()?;
+        CaveSystem::new(connections)
+    }
 
-        self.adjacency_matrix[cave_id as usize]
-            .iter()
-            .enumerate()
-            .filter(|(_, &is_connected)| is_connected)
-            .map(|(id, _)| &self.id_cave_map[&(id as u8)])
-            .collect()
+    /// Lazily enumerate the paths between the given begin & end caves.
+    /// Small caves are visited as allowed by the given visit policy.
+    fn paths<'a>(&'a self, policy: &'a VisitPolicy, begin: &Cave, end: &Cave) -> Paths<'a> {
+        Paths::new(self, policy, begin, end)
     }
+}
 
-    /// Perform depth first search on the this cave system to find the no. of paths
-    /// between the given begin & end caves.
-    fn dfs<'a>(&'a self, begin: &Cave, end: &Cave, visited_small: Vec<&Cave>) -> u32 {
-        if begin == end {
-            // base case: found target end cave
-            1
-        } else {
-            // recursive case: recursively search for paths from begin to end caves
-            let mut total_paths = 0;
-            for cave in self.connected(begin) {
-                let next_cave = match cave {
-                    // big caves can be visited any number of times
-                    Cave::Big(_) => Some(cave),
-                    // small caves:
-                    small_cave @ Cave::Small(_) => match small_cave {
-                        // all small caves can be visited at least once
-                        _ if !visited_small.contains(&small_cave) => Some(cave),
-                        // one small cave can be visited at most twice
-                        _ if visited_small.len() == visited_small.iter().collect::<HashSet<_>>().len()
-                            => Some(cave),
-                        _ => None,
-                    },
-                    // end cave can be visited next, which will complete the
-                    // path and end the recursion.
-                    Cave::End => Some(cave),
-                    _ => None,
-                };
-                
-                total_paths += next_cave.map_or(0, |cave| {
-                    // track small caves visited
-                    let mut visited_small = visited_small.clone();
-                    if let Cave::Small(_) = cave {
-                        visited_small.push(cave);
-                    }
-                    self.dfs(cave, end, visited_small)
-                });
+fn main() {
+    // parse options from command line arguments:
+    // --paths to print each path from start to end.
+    // --limit=N to print at most N paths.
+    // --sort to print paths in lexicographic order of their cave names.
+    //   Sorting collects every path into memory before printing.
+    // --policy=POLICY to limit visits to small caves by the visit policy:
+    //   small-once, one-small-twice, small-twice:K or per-cave:NAME=LIMIT,..
+    //   (default: one-small-twice)
+    // --dot=FILE to write the cave system as a Graphviz DOT graph to the file.
+    // --dot-counts to label each connection in the DOT graph with the no. of times
+    //   the counted paths traverse it.
+    let (mut print_paths, mut limit, mut sort) = (false, None, false);
+    let mut policy = VisitPolicy::OneSmallTwice;
+    let (mut dot_path, mut dot_counts) = (None, false);
+    for arg in env::args().skip(1) {
+        match arg.split_once('=').unwrap_or((&arg, "")) {
+            ("--paths", _) => print_paths = true,
+            ("--limit", value) => {
+                limit = Some(value.parse().expect("Expected limit to be an integer"))
             }
-
-            total_paths
+            ("--sort", _) => sort = true,
+            ("--dot", value) => dot_path = Some(value.to_string()),
+            ("--dot-counts", _) => dot_counts = true,
+            ("--policy", value) => {
+                policy = VisitPolicy::parse(value)
+                    .unwrap_or_else(|| panic!("Unsupported visit policy: {}", value))
+            }
+            _ => panic!("Unsupported option: {}", arg),
         }
     }
-}
 
-fn main() {
     // read the cave system connections from stdin
-    let connections: Vec<(Cave, Cave)> = stdin()
-        .lock()
-        .lines()
-        .map(|line| line.expect("Failed to read cave system connections from stdin."))
-        .map(|line| {
-            // closure to wrap caves into cave types
-            let wrap_cave = |name: String| -> Cave {
-                match name.as_str() {
-                    "start" => Cave::Start,
-                    "end" => Cave::End,
-                    _ if name == name.to_lowercase() => Cave::Small(name),
-                    _ => Cave::Big(name),
-                }
-            };
+    let system = CaveSystem::parse(
+        stdin()
+            .lock()
+            .lines()
+            .map(|line| line.expect("Failed to read cave system connections from stdin.")),
+    )
+    .unwrap_or_else(|err| panic!("{}", err));
 
-            if let [src, dest] = line.split("-").collect::<Vec<&str>>().as_slice() {
-                (wrap_cave(src.to_string()), wrap_cave(dest.to_string()))
-            } else {
-                panic!("Expected connections to be in the format: SRC-DEST");
-            }
-        })
-        .collect();
-    let system = CaveSystem::new(connections);
+    if print_paths {
+        let paths = system.paths(&policy, &Cave::Start, &Cave::End).map(|path| {
+            path.iter()
+                .map(|cave| cave.to_string())
+                .collect::<Vec<_>>()
+                .join(",")
+        });
+        let limit = limit.unwrap_or(usize::MAX);
+        if sort {
+            let mut paths: Vec<_> = paths.collect();
+            paths.sort_unstable();
+            paths
+                .into_iter()
+                .take(limit)
+                .for_each(|path| println!("{}", path));
+        } else {
+            paths.take(limit).for_each(|path| println!("{}", path));
+        }
+    }
 
     // find paths from start to the end cavews
+    let mut counter = PathCounter::new(&system, &policy, &Cave::End);
     println!(
         "No. of distinct paths from start to end: {}",
-        system.dfs(&Cave::Start, &Cave::End, vec![])
-    )
+        counter.count(&Cave::Start)
+    );
+
+    if let Some(dot_path) = dot_path {
+        let edge_counts = if dot_counts {
+            Some(counter.edge_counts(&Cave::Start))
+        } else {
+            None
+        };
+        let mut dot_file =
+            BufWriter::new(File::create(&dot_path).expect("Failed to create DOT file"));
+        write_dot(&mut dot_file, &system, edge_counts.as_ref()).expect("Failed to write DOT file");
+    }
 }
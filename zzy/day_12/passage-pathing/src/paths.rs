@@ -0,0 +1,117 @@
+//!
+//! Anti Helmet
+//! Advent of Code
+//! Day 12: Passage Pathing
+//! Path Enumeration
+//!
+
+use crate::policy::VisitPolicy;
+use crate::{Cave, CaveSystem};
+
+#[cfg(test)]
+mod tests;
+
+/// Iterator that lazily yields each path between two caves in a cave system
+/// as the list of caves along the path, in depth first order, visiting small caves
+/// as allowed by a visit policy.
+/// Only the path currently being explored is kept in memory.
+pub struct Paths<'a> {
+    system: &'a CaveSystem,
+    policy: &'a VisitPolicy,
+    end: u8,
+    /// Ids of the caves on the path currently being explored.
+    path: Vec<u8>,
+    /// Id of the next cave to try to visit from each cave on the path.
+    next_ids: Vec<usize>,
+    /// No. of times each cave has been visited on the path, indexed by cave id.
+    n_visits: Vec<u8>,
+    /// No. of small caves visited more than once on the path.
+    n_repeated: usize,
+}
+impl<'a> Paths<'a> {
+    /// Create an iterator over the paths between the given begin & end caves.
+    pub fn new(system: &'a CaveSystem, policy: &'a VisitPolicy, begin: &Cave, end: &Cave) -> Self {
+        let begin_id = system.cave_id_map[begin];
+        let mut n_visits = vec![0; system.id_cave_map.len()];
+        n_visits[begin_id as usize] = 1;
+        Self {
+            system,
+            policy,
+            end: system.cave_id_map[end],
+            path: vec![begin_id],
+            next_ids: vec![0],
+            n_visits,
+            n_repeated: 0,
+        }
+    }
+
+    /// Check whether the given cave can be visited next on the current path.
+    fn can_visit(&self, cave_id: u8) -> bool {
+        match &self.system.id_cave_map[&cave_id] {
+            // big caves can be visited any number of times
+            Cave::Big(_) => true,
+            // small caves can be visited as allowed by the visit policy
+            Cave::Small(name) => {
+                self.policy
+                    .can_visit(name, self.n_visits[cave_id as usize], self.n_repeated)
+            }
+            // start cave cannot be revisited
+            Cave::Start => false,
+            Cave::End => true,
+        }
+    }
+
+    /// Backtrack by removing the last cave from the current path.
+    fn pop(&mut self) {
+        if let Some(cave_id) = self.path.pop() {
+            self.next_ids.pop();
+            let n_visits = &mut self.n_visits[cave_id as usize];
+            if *n_visits == 2 && matches!(self.system.id_cave_map[&cave_id], Cave::Small(_)) {
+                self.n_repeated -= 1;
+            }
+            *n_visits -= 1;
+        }
+    }
+}
+impl<'a> Iterator for Paths<'a> {
+    type Item = Vec<&'a Cave>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let &cave_id = self.path.last()?;
+            // find the next cave connected to the last cave on the path
+            let connections = &self.system.adjacency_matrix[cave_id as usize];
+            let next_id = (*self.next_ids.last().unwrap()..connections.len())
+                .find(|&id| connections[id] && self.can_visit(id as u8));
+            let next_id = match next_id {
+                Some(next_id) => next_id as u8,
+                None => {
+                    // exhausted paths through the last cave: backtrack
+                    self.pop();
+                    continue;
+                }
+            };
+            *self.next_ids.last_mut().unwrap() = next_id as usize + 1;
+
+            if next_id == self.end {
+                // found target end cave: yield the path without extending it
+                let system = self.system;
+                return Some(
+                    self.path
+                        .iter()
+                        .chain(Some(&next_id))
+                        .map(|id| &system.id_cave_map[id])
+                        .collect(),
+                );
+            }
+            // track visit to cave & explore paths through it
+            let n_visits = &mut self.n_visits[next_id as usize];
+            *n_visits += 1;
+            if *n_visits == 2 && matches!(self.system.id_cave_map[&next_id], Cave::Small(_)) {
+                self.n_repeated += 1;
+            }
+            self.path.push(next_id);
+            self.next_ids.push(0);
+        }
+    }
+}
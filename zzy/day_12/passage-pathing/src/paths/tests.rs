@@ -0,0 +1,87 @@
+//!
+//! Anti Helmet
+//! Advent of Code
+//! Day 12: Passage Pathing
+//! Unit Tests
+//!
+
+use super::*;
+use crate::fixtures::system;
+use std::collections::HashSet;
+
+fn path_strs(system: &CaveSystem) -> Vec<String> {
+    Paths::new(
+        system,
+        &VisitPolicy::OneSmallTwice,
+        &Cave::Start,
+        &Cave::End,
+    )
+    .map(|path| {
+        path.iter()
+            .map(|cave| cave.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    })
+    .collect()
+}
+
+#[test]
+fn test_paths_example() {
+    let system = system(&["start-A", "start-b", "A-c", "A-b", "b-d", "A-end", "b-end"]);
+    let paths = path_strs(&system);
+    assert_eq!(paths.len(), 36);
+    assert_eq!(paths.iter().collect::<HashSet<_>>().len(), 36);
+    for path in [
+        "start,A,b,A,b,A,c,A,end",
+        "start,b,d,b,A,c,A,end",
+        "start,A,c,A,b,end",
+        "start,b,end",
+    ]
+    .iter()
+    {
+        assert!(
+            paths.iter().any(|other| other == path),
+            "Missing path: {}",
+            path
+        );
+    }
+    // no path visits more than one small cave twice
+    for path in paths.iter() {
+        let smalls: Vec<_> = path
+            .split(',')
+            .filter(|name| matches!(Cave::parse(name), Cave::Small(_)))
+            .collect();
+        let n_unique = smalls.iter().collect::<HashSet<_>>().len();
+        assert!(smalls.len() - n_unique <= 1, "Invalid path: {}", path);
+    }
+}
+
+#[test]
+fn test_paths_lazy() {
+    let system = system(&["start-a", "a-end", "start-end"]);
+    let mut paths = Paths::new(
+        &system,
+        &VisitPolicy::OneSmallTwice,
+        &Cave::Start,
+        &Cave::End,
+    );
+    assert_eq!(
+        paths.next(),
+        Some(vec![&Cave::Start, &Cave::parse("a"), &Cave::End])
+    );
+    assert_eq!(paths.next(), Some(vec![&Cave::Start, &Cave::End]));
+    assert_eq!(paths.next(), None);
+    assert_eq!(paths.next(), None);
+}
+
+#[test]
+fn test_parse_rejects_connected_big_caves() {
+    // paths could move back & forth between connected big caves forever
+    assert_eq!(
+        CaveSystem::parse(["start-A", "A-B", "B-end"].iter()).err(),
+        Some(
+            "Expected no connections between big caves, which allow infinitely many paths: A-B"
+                .to_string()
+        )
+    );
+}
@@ -0,0 +1,95 @@
+//!
+//! Anti Helmet
+//! Advent of Code
+//! Day 12: Passage Pathing
+//! Visit Policies
+//!
+
+use std::collections::HashMap;
+
+#[cfg(test)]
+mod tests;
+
+/// Defines how many times small caves may be visited on a path.
+/// Big caves can always be visited any number of times.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VisitPolicy {
+    /// Every small cave can be visited at most once.
+    SmallOnce,
+    /// Every small cave can be visited once & one small cave at most twice.
+    OneSmallTwice,
+    /// Every small cave can be visited once & up to the given no. of small caves
+    /// at most twice.
+    SmallTwice(usize),
+    /// Each small cave can be visited up to its own limit of visits, defaulting to the
+    /// default limit for small caves without one.
+    PerCave {
+        limits: HashMap<String, u8>,
+        default_limit: u8,
+    },
+}
+impl VisitPolicy {
+    /// Parse a visit policy from its name:
+    /// - "small-once", "one-small-twice" for the built in policies.
+    /// - "small-twice:K" to allow K small caves to be visited twice.
+    /// - "per-cave:NAME=LIMIT,.." to limit visits to each named small cave,
+    ///   with "*=LIMIT" to set the default limit (default: 1).
+    ///
+    /// Returns None if the visit policy could not be parsed.
+    pub fn parse(policy_str: &str) -> Option<Self> {
+        match policy_str.split_once(':').unwrap_or((policy_str, "")) {
+            ("small-once", "") => Some(VisitPolicy::SmallOnce),
+            ("one-small-twice", "") => Some(VisitPolicy::OneSmallTwice),
+            ("small-twice", n_caves) => Some(VisitPolicy::SmallTwice(n_caves.parse().ok()?)),
+            ("per-cave", limits_str) => {
+                let mut limits = HashMap::new();
+                let mut default_limit = 1;
+                for limit_str in limits_str.split(',') {
+                    let (name, limit) = limit_str.split_once('=')?;
+                    let limit = limit.parse().ok()?;
+                    if name == "*" {
+                        default_limit = limit;
+                    } else {
+                        limits.insert(name.to_string(), limit);
+                    }
+                }
+                Some(VisitPolicy::PerCave {
+                    limits,
+                    default_limit,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Max no. of times the small cave with the given name can be visited.
+    pub fn limit(&self, name: &str) -> u8 {
+        match self {
+            VisitPolicy::SmallOnce => 1,
+            VisitPolicy::OneSmallTwice | VisitPolicy::SmallTwice(_) => 2,
+            VisitPolicy::PerCave {
+                limits,
+                default_limit,
+            } => limits.get(name).copied().unwrap_or(*default_limit),
+        }
+    }
+
+    /// Max no. of small caves that can be visited more than once, None if unbounded.
+    pub fn max_repeated(&self) -> Option<usize> {
+        match self {
+            VisitPolicy::SmallOnce => Some(0),
+            VisitPolicy::OneSmallTwice => Some(1),
+            VisitPolicy::SmallTwice(n_caves) => Some(*n_caves),
+            VisitPolicy::PerCave { .. } => None,
+        }
+    }
+
+    /// Check whether the small cave with the given name can be visited again, given
+    /// its no. of visits so far & the no. of small caves already visited more than once.
+    pub fn can_visit(&self, name: &str, n_visits: u8, n_repeated: usize) -> bool {
+        // revisiting a small cave for the first time makes it repeated
+        let is_repeating = n_visits == 1;
+        n_visits < self.limit(name)
+            && !(is_repeating && matches!(self.max_repeated(), Some(max) if n_repeated >= max))
+    }
+}
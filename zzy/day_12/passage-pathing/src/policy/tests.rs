@@ -0,0 +1,57 @@
+//!
+//! Anti Helmet
+//! Advent of Code
+//! Day 12: Passage Pathing
+//! Unit Tests
+//!
+
+use super::*;
+
+#[test]
+fn test_parse() {
+    assert_eq!(
+        VisitPolicy::parse("small-once"),
+        Some(VisitPolicy::SmallOnce)
+    );
+    assert_eq!(
+        VisitPolicy::parse("one-small-twice"),
+        Some(VisitPolicy::OneSmallTwice)
+    );
+    assert_eq!(
+        VisitPolicy::parse("small-twice:3"),
+        Some(VisitPolicy::SmallTwice(3))
+    );
+    assert_eq!(
+        VisitPolicy::parse("per-cave:ab=3,*=2"),
+        Some(VisitPolicy::PerCave {
+            limits: [("ab".to_string(), 3)].iter().cloned().collect(),
+            default_limit: 2,
+        })
+    );
+    assert_eq!(VisitPolicy::parse("small-once:1"), None);
+    assert_eq!(VisitPolicy::parse("small-twice:many"), None);
+    assert_eq!(VisitPolicy::parse("per-cave:ab"), None);
+}
+
+#[test]
+fn test_can_visit() {
+    let policy = VisitPolicy::SmallOnce;
+    assert!(policy.can_visit("a", 0, 0));
+    assert!(!policy.can_visit("a", 1, 0));
+
+    let policy = VisitPolicy::OneSmallTwice;
+    assert!(policy.can_visit("a", 1, 0));
+    assert!(!policy.can_visit("a", 1, 1));
+    assert!(!policy.can_visit("a", 2, 1));
+
+    let policy = VisitPolicy::SmallTwice(2);
+    assert!(policy.can_visit("a", 1, 1));
+    assert!(!policy.can_visit("a", 1, 2));
+
+    let policy = VisitPolicy::parse("per-cave:a=3,b=0").unwrap();
+    assert!(policy.can_visit("a", 2, 5));
+    assert!(!policy.can_visit("a", 3, 0));
+    assert!(!policy.can_visit("b", 0, 0));
+    assert!(policy.can_visit("c", 0, 0));
+    assert!(!policy.can_visit("c", 1, 0));
+}
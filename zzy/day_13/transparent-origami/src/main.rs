@@ -5,78 +5,55 @@
 //!
 
 use regex::Regex;
-use std::collections::HashSet;
-use std::io::{stdin, BufRead};
+use std::env;
+use std::fs::File;
+use std::io::{stdin, BufRead, BufWriter};
 
-/// Defines a dot on the Origami Paper represented as 2D (x, y) points.
-/// Where the x-axis advances the dot to the right and the y-axis advances the dot downwards.
-#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
-struct Dot {
-    x: u16,
-    y: u16,
-}
-
-/// Defines a fold instruction to be applied to the dots on the origami paper
-enum Fold {
-    X(u16),
-    Y(u16),
-}
-
-/// Apply the fold instruction to the given origami dot.
-/// Returns then dot after folding on origami paper.
-fn fold(dot: Dot, instruction: &Fold) -> Dot {
-    match *instruction {
-        Fold::X(location) if dot.x <= location => dot,
-        Fold::X(location) => Dot {
-            // compute the negative offset: x coord - location
-            x: location - (dot.x - location),
-            ..dot
-        },
-        Fold::Y(location) if dot.y <= location => dot,
-        Fold::Y(location) => Dot {
-            // compute the negative offset: y coord - location
-            y: location - (dot.y - location),
-            ..dot
-        },
-    }
-}
+mod ocr;
+mod paper;
+mod svg;
+use ocr::recognise;
+use paper::{Dot, Fold, Paper};
+use svg::{write_svg, Stage};
 
 /// Render & and Display the given origami dots a 2D text space.
 /// Returns the dots rendered as a text string.
 fn display(dots: &[Dot]) -> String {
-    if dots.len() < 1 {
+    if dots.is_empty() {
         panic!("Expected to be given at least one dot to display");
     }
     // compute bounds of display
-    let min_x = dots.iter()
-        .map(|dot| dot.x)
-        .min().unwrap();
-    let max_x = dots.iter()
-        .map(|dot| dot.x)
-        .max().unwrap();
-    let min_y = dots.iter()
-        .map(|dot| dot.y)
-        .min().unwrap();
-    let max_y = dots.iter()
-        .map(|dot| dot.y)
-        .max().unwrap();
-    
+    let min_x = dots.iter().map(|dot| dot.x).min().unwrap();
+    let max_x = dots.iter().map(|dot| dot.x).max().unwrap();
+    let min_y = dots.iter().map(|dot| dot.y).min().unwrap();
+    let max_y = dots.iter().map(|dot| dot.y).max().unwrap();
+
     // draw dots on display buffer.
-    let row_len = (max_x-min_x+1) as usize;
-    let n_rows = (max_y-min_y+1) as usize;
+    let row_len = (max_x - min_x + 1) as usize;
+    let n_rows = (max_y - min_y + 1) as usize;
     let mut buffer = vec![vec!["."; row_len]; n_rows];
-    for &Dot{x, y} in dots {
-        buffer[(y-min_y) as usize ][(x-min_x) as usize] = "#"
+    for &Dot { x, y } in dots {
+        buffer[(y - min_y) as usize][(x - min_x) as usize] = "#"
     }
 
-    let render: String = buffer.into_iter()
+    let render: String = buffer
+        .into_iter()
         .map(|row| format!("{}\n", row.as_slice().join("")))
         .collect();
     render
 }
 
-
 fn main() {
+    // parse program options:
+    // --svg=FILE to write an SVG illustrating each stage of the fold sequence to the file.
+    let mut svg_path = None;
+    for arg in env::args().skip(1) {
+        match arg.split_once('=').unwrap_or((&arg, "")) {
+            ("--svg", value) if !value.is_empty() => svg_path = Some(value.to_string()),
+            _ => panic!("Unsupported option: {}", arg),
+        }
+    }
+
     // parse origami paper dots & folding instructions from stdin
     let lines: Vec<_> = stdin()
         .lock()
@@ -84,15 +61,15 @@ fn main() {
         .map(|rst| rst.expect("Failed to to read input lines from STDIN"))
         .collect();
 
-    let input_segs: Vec<_> = lines.split(|line| line == "").collect();
+    let input_segs: Vec<_> = lines.split(|line| line.is_empty()).collect();
     if input_segs.len() != 2 {
         panic!("Expected 2 input segments separated by a empty line");
     }
 
-    let dots: HashSet<Dot> = input_segs[0]
-        .into_iter()
+    let dots: Vec<Dot> = input_segs[0]
+        .iter()
         .map(|line| {
-            let mut dot_splits = line.split(",");
+            let mut dot_splits = line.split(',');
             let (x, y) = dot_splits
                 .next()
                 .zip(dot_splits.next())
@@ -107,34 +84,70 @@ fn main() {
         })
         .collect();
 
-    let instruction_regex = Regex::new(r"fold along (?P<axis>[xy])=(?P<value>\d+)").unwrap();
+    // supports axis folds "x=N" & "y=N" and diagonal folds "y=x+N" & "y=-x+N"
+    let instruction_regex = Regex::new(
+        r"^fold along (?P<axis>[xy])=(?:(?P<value>-?\d+)|(?P<sign>-?)x(?P<offset>[+-]\d+)?)$",
+    )
+    .unwrap();
     let instructions: Vec<_> = input_segs[1]
-        .into_iter()
+        .iter()
         .map(|line| {
             let caps = instruction_regex
                 .captures(line)
                 .expect("Failed to parse folding instructions");
-            let value = caps["value"].parse().expect("Failed to parse integer");
-            match &caps["axis"] {
-                "x" => Fold::X(value),
-                "y" => Fold::Y(value),
-                _ => panic!("Unsupported instruction axis: Expected 'y' or 'x'"),
+            let parse = |name| {
+                caps.name(name).map_or(0, |value| {
+                    value
+                        .as_str()
+                        .trim_start_matches('+')
+                        .parse()
+                        .expect("Failed to parse integer")
+                })
+            };
+            match (&caps["axis"], caps.name("sign").map(|sign| sign.as_str())) {
+                ("x", None) => Fold::X(parse("value")),
+                ("y", None) => Fold::Y(parse("value")),
+                ("y", Some("")) => Fold::Diagonal(parse("offset")),
+                ("y", Some("-")) => Fold::AntiDiagonal(parse("offset")),
+                _ => panic!("Unsupported instruction: Expected diagonal folds along 'y'"),
             }
         })
         .collect();
 
-    // apply folding instructions to dots
-    let folded_dots: Vec<_> = dots
-        .into_iter()
-        .map(|inital_dot| {
-            instructions
-                .iter()
-                .fold(inital_dot, fold)
-        })
-        // remove overlapping dots by finding unique folded dots.
-        .collect::<HashSet<_>>()
-        .into_iter()
-        .collect();
-    
+    // apply folding instructions to dots, reporting the dots visible after each fold
+    // & recording each stage of the fold sequence if exporting it as an SVG
+    let mut paper = Paper::new(dots);
+    let mut stages = Vec::new();
+    let mut record = |fold: Option<Fold>, paper: &Paper| {
+        if svg_path.is_some() {
+            stages.push(Stage {
+                fold,
+                dots: paper.dots.iter().copied().collect(),
+            });
+        }
+    };
+    record(None, &paper);
+    for instruction in instructions.iter() {
+        let n_visible = paper
+            .fold(instruction)
+            .unwrap_or_else(|err| panic!("{}", err));
+        println!("After {}: {} dots visible", instruction, n_visible);
+        record(Some(*instruction), &paper);
+    }
+
+    if let Some(svg_path) = svg_path {
+        let mut svg_file =
+            BufWriter::new(File::create(&svg_path).expect("Failed to create SVG file"));
+        write_svg(&mut svg_file, &stages).expect("Failed to write SVG file");
+    }
+    let folded_dots: Vec<_> = paper.dots.into_iter().collect();
+
     print!("{}", display(folded_dots.as_slice()));
+
+    // read the folded dots as letters
+    let recognition = recognise(&folded_dots);
+    println!("Code: {}", recognition.text);
+    for position in recognition.unrecognised {
+        println!("Unrecognised glyph at position {}", position + 1);
+    }
 }
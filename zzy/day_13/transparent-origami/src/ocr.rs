@@ -0,0 +1,112 @@
+//!
+//! Anti Helmet
+//! Advent of Code
+//! Day 13: Transparent Origami
+//! Letter Recognition
+//!
+
+use crate::paper::Dot;
+
+#[cfg(test)]
+mod tests;
+
+/// Width of each glyph in the block letter font.
+pub const GLYPH_WIDTH: usize = 4;
+/// Height of each glyph in the block letter font.
+pub const GLYPH_HEIGHT: usize = 6;
+/// Columns between the start of each glyph: the glyph's width & a blank spacing column.
+const GLYPH_STRIDE: usize = GLYPH_WIDTH + 1;
+
+/// Glyphs of the 4x6 block letter font, drawn as rows of '#' dots & '.' blanks.
+const FONT: [(char, [&str; GLYPH_HEIGHT]); 16] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Placeholder character for glyphs that could not be recognised.
+pub const UNKNOWN_CHAR: char = '?';
+
+/// Text recognised from dots drawn in the block letter font.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Recognition {
+    /// Recognised text, with unrecognised glyphs replaced by UNKNOWN_CHAR.
+    pub text: String,
+    /// Positions of the glyphs that could not be recognised, starting from 0.
+    pub unrecognised: Vec<usize>,
+}
+
+/// Encode a glyph's rows of dots as a bitmask, one bit per position in row major order.
+fn encode(rows: impl Iterator<Item = impl Iterator<Item = bool>>) -> u32 {
+    rows.flatten()
+        .fold(0, |bits, is_dot| (bits << 1) | is_dot as u32)
+}
+
+/// Recognise the text spelt by the given dots drawn in the 4x6 block letter font,
+/// with glyphs laid out left to right from the top left most dot, separated by a blank column.
+pub fn recognise(dots: &[Dot]) -> Recognition {
+    let min_x = dots.iter().map(|dot| dot.x).min().unwrap_or(0);
+    let min_y = dots.iter().map(|dot| dot.y).min().unwrap_or(0);
+    let max_x = dots.iter().map(|dot| dot.x).max().unwrap_or(0);
+    let width = if dots.is_empty() {
+        0
+    } else {
+        (max_x - min_x) as usize + 1
+    };
+
+    // draw dots on a buffer sized to hold whole glyphs
+    let n_glyphs = (width + GLYPH_STRIDE - 1) / GLYPH_STRIDE;
+    let mut buffer = vec![vec![false; n_glyphs * GLYPH_STRIDE]; GLYPH_HEIGHT];
+    let mut overflow = vec![false; n_glyphs];
+    for dot in dots {
+        let (x, y) = ((dot.x - min_x) as usize, (dot.y - min_y) as usize);
+        if y < GLYPH_HEIGHT && x % GLYPH_STRIDE < GLYPH_WIDTH {
+            buffer[y][x] = true;
+        } else {
+            // dot falls outside the glyph's cell: in its spacing or below the glyph
+            overflow[x / GLYPH_STRIDE] = true;
+        }
+    }
+
+    let font: Vec<_> = FONT
+        .iter()
+        .map(|(letter, rows)| {
+            let bits = encode(rows.iter().map(|row| row.chars().map(|c| c == '#')));
+            (*letter, bits)
+        })
+        .collect();
+    let mut recognition = Recognition {
+        text: String::new(),
+        unrecognised: Vec::new(),
+    };
+    for (i_glyph, &is_overflowing) in overflow.iter().enumerate() {
+        let begin_x = i_glyph * GLYPH_STRIDE;
+        let bits = encode(
+            buffer
+                .iter()
+                .map(|row| row[begin_x..begin_x + GLYPH_WIDTH].iter().copied()),
+        );
+        let letter = font
+            .iter()
+            .find(|&&(_, glyph_bits)| glyph_bits == bits && !is_overflowing)
+            .map(|&(letter, _)| letter);
+        recognition.text.push(letter.unwrap_or(UNKNOWN_CHAR));
+        if letter.is_none() {
+            recognition.unrecognised.push(i_glyph);
+        }
+    }
+    recognition
+}
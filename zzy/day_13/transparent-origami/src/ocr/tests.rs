@@ -0,0 +1,57 @@
+//!
+//! Anti Helmet
+//! Advent of Code
+//! Day 13: Transparent Origami
+//! Unit Tests
+//!
+
+use super::*;
+
+/// Draw the given text in the block letter font as dots offset by the given origin.
+fn draw(text: &str, origin: (i32, i32)) -> Vec<Dot> {
+    let mut dots = Vec::new();
+    for (i_glyph, letter) in text.chars().enumerate() {
+        let (_, rows) = FONT.iter().find(|(glyph, _)| *glyph == letter).unwrap();
+        for (y, row) in rows.iter().enumerate() {
+            for (x, c) in row.chars().enumerate() {
+                if c == '#' {
+                    dots.push(Dot {
+                        x: origin.0 + (i_glyph * GLYPH_STRIDE + x) as i32,
+                        y: origin.1 + y as i32,
+                    });
+                }
+            }
+        }
+    }
+    dots
+}
+
+#[test]
+fn test_recognise_font() {
+    let alphabet: String = FONT.iter().map(|(letter, _)| letter).collect();
+    let recognition = recognise(&draw(&alphabet, (0, 0)));
+    assert_eq!(recognition.text, alphabet);
+    assert!(recognition.unrecognised.is_empty());
+
+    let recognition = recognise(&draw("ZJ", (3, 7)));
+    assert_eq!(recognition.text, "ZJ");
+}
+
+#[test]
+fn test_recognise_unknown() {
+    let mut dots = draw("HELLO", (0, 0));
+    // smudge the second L with a dot in its blank space
+    dots.push(Dot { x: 16, y: 1 });
+    // smudge the E with a dot below it
+    dots.push(Dot { x: 5, y: 6 });
+    let recognition = recognise(&dots);
+    assert_eq!(recognition.text, "H?L?O");
+    assert_eq!(recognition.unrecognised, vec![1, 3]);
+}
+
+#[test]
+fn test_recognise_empty() {
+    let recognition = recognise(&[]);
+    assert_eq!(recognition.text, "");
+    assert!(recognition.unrecognised.is_empty());
+}
@@ -0,0 +1,107 @@
+//!
+//! Anti Helmet
+//! Advent of Code
+//! Day 13: Transparent Origami
+//! Origami Paper
+//!
+
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::fmt::{self, Display, Formatter};
+
+#[cfg(test)]
+mod tests;
+
+/// Defines a dot on the Origami Paper represented as 2D (x, y) points.
+/// Where the x-axis advances the dot to the right and the y-axis advances the dot downwards.
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
+pub struct Dot {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// Defines a fold instruction to be applied to the dots on the origami paper.
+/// Each fold folds the half of the paper past the fold line onto the other half.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum Fold {
+    /// Fold along the vertical line x=N, folding the right half leftwards.
+    X(i32),
+    /// Fold along the horizontal line y=N, folding the bottom half upwards.
+    Y(i32),
+    /// Fold along the diagonal line y=x+N, folding the half below the line
+    /// up & to the right.
+    Diagonal(i32),
+    /// Fold along the anti-diagonal line y=-x+N, folding the half below the line
+    /// up & to the left.
+    AntiDiagonal(i32),
+}
+impl Fold {
+    /// Apply the fold instruction to the given origami dot.
+    /// Dots on or before the fold line are left in place, while dots past the fold line
+    /// are mirrored across it, even those mirrored past the edge of the paper.
+    /// Returns the dot after folding on origami paper, or None if the mirrored dot's
+    /// coordinates do not fit in i32.
+    pub fn apply(&self, dot: Dot) -> Option<Dot> {
+        // mirror in i64 as mirrored coordinates may fall outside the range of i32
+        let (x, y) = (i64::from(dot.x), i64::from(dot.y));
+        let (x, y) = match *self {
+            Fold::X(location) if x <= i64::from(location) => (x, y),
+            Fold::X(location) => (2 * i64::from(location) - x, y),
+            Fold::Y(location) if y <= i64::from(location) => (x, y),
+            Fold::Y(location) => (x, 2 * i64::from(location) - y),
+            Fold::Diagonal(offset) if y <= x + i64::from(offset) => (x, y),
+            // mirror by swapping coordinates relative to the line
+            Fold::Diagonal(offset) => (y - i64::from(offset), x + i64::from(offset)),
+            Fold::AntiDiagonal(offset) if y <= i64::from(offset) - x => (x, y),
+            Fold::AntiDiagonal(offset) => (i64::from(offset) - y, i64::from(offset) - x),
+        };
+        Some(Dot {
+            x: i32::try_from(x).ok()?,
+            y: i32::try_from(y).ok()?,
+        })
+    }
+}
+impl Display for Fold {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            Fold::X(location) => write!(f, "fold along x={}", location),
+            Fold::Y(location) => write!(f, "fold along y={}", location),
+            Fold::Diagonal(0) => write!(f, "fold along y=x"),
+            Fold::Diagonal(offset) => write!(f, "fold along y=x{:+}", offset),
+            Fold::AntiDiagonal(0) => write!(f, "fold along y=-x"),
+            Fold::AntiDiagonal(offset) => write!(f, "fold along y=-x{:+}", offset),
+        }
+    }
+}
+
+/// Models a sheet of transparent origami paper marked with dots.
+pub struct Paper {
+    /// Dots visible on the paper: dots that overlap after folding are only kept once.
+    pub dots: HashSet<Dot>,
+}
+impl Paper {
+    pub fn new(dots: impl IntoIterator<Item = Dot>) -> Self {
+        Self {
+            dots: dots.into_iter().collect(),
+        }
+    }
+
+    /// Fold the paper with the given fold instruction, merging overlapping dots.
+    /// Returns the no. of dots visible after the fold, or an error message if a dot
+    /// would be mirrored past the range of coordinates, leaving the paper unfolded.
+    pub fn fold(&mut self, instruction: &Fold) -> Result<usize, String> {
+        self.dots = self
+            .dots
+            .iter()
+            .map(|&dot| {
+                instruction.apply(dot).ok_or_else(|| {
+                    format!(
+                        "Failed to {}: dot at {},{} would be mirrored out of range",
+                        instruction, dot.x, dot.y
+                    )
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(self.dots.len())
+    }
+}
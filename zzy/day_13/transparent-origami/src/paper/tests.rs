@@ -0,0 +1,82 @@
+//!
+//! Anti Helmet
+//! Advent of Code
+//! Day 13: Transparent Origami
+//! Unit Tests
+//!
+
+use super::*;
+
+fn dot(x: i32, y: i32) -> Dot {
+    Dot { x, y }
+}
+
+#[test]
+fn test_fold_axis() {
+    assert_eq!(Fold::X(5).apply(dot(3, 2)), Some(dot(3, 2)));
+    assert_eq!(Fold::X(5).apply(dot(5, 2)), Some(dot(5, 2)));
+    assert_eq!(Fold::X(5).apply(dot(8, 2)), Some(dot(2, 2)));
+    assert_eq!(Fold::Y(7).apply(dot(1, 10)), Some(dot(1, 4)));
+    // dots farther past the fold than the fold location mirror past the edge
+    assert_eq!(Fold::Y(2).apply(dot(1, 7)), Some(dot(1, -3)));
+    // coordinates are mirrored without overflow, rejecting dots mirrored out of range
+    assert_eq!(
+        Fold::X(1_500_000_000).apply(dot(1_500_000_001, 0)),
+        Some(dot(1_499_999_999, 0))
+    );
+    assert_eq!(Fold::Y(-2_000_000_000).apply(dot(0, 2_000_000_000)), None);
+}
+
+#[test]
+fn test_fold_diagonal() {
+    assert_eq!(Fold::Diagonal(0).apply(dot(3, 1)), Some(dot(3, 1)));
+    assert_eq!(Fold::Diagonal(0).apply(dot(1, 3)), Some(dot(3, 1)));
+    assert_eq!(Fold::Diagonal(2).apply(dot(0, 5)), Some(dot(3, 2)));
+    assert_eq!(Fold::AntiDiagonal(4).apply(dot(1, 1)), Some(dot(1, 1)));
+    assert_eq!(Fold::AntiDiagonal(4).apply(dot(3, 4)), Some(dot(0, 1)));
+    // dots on the fold line stay in place
+    assert_eq!(Fold::AntiDiagonal(4).apply(dot(1, 3)), Some(dot(1, 3)));
+    assert_eq!(
+        Fold::Diagonal(-2_000_000_000).apply(dot(2_100_000_000, 200_000_000)),
+        None
+    );
+}
+
+#[test]
+fn test_fold_display() {
+    assert_eq!(Fold::X(5).to_string(), "fold along x=5");
+    assert_eq!(Fold::Diagonal(0).to_string(), "fold along y=x");
+    assert_eq!(Fold::Diagonal(-2).to_string(), "fold along y=x-2");
+    assert_eq!(Fold::AntiDiagonal(3).to_string(), "fold along y=-x+3");
+}
+
+#[test]
+fn test_paper_fold() {
+    let mut paper = Paper::new(vec![
+        dot(6, 10),
+        dot(0, 14),
+        dot(9, 10),
+        dot(0, 3),
+        dot(10, 4),
+        dot(4, 11),
+        dot(6, 0),
+        dot(6, 12),
+        dot(4, 1),
+        dot(0, 13),
+        dot(10, 12),
+        dot(3, 4),
+        dot(3, 0),
+        dot(8, 4),
+        dot(1, 10),
+        dot(2, 14),
+        dot(8, 10),
+        dot(9, 0),
+    ]);
+    assert_eq!(paper.fold(&Fold::Y(7)), Ok(17));
+    assert_eq!(paper.fold(&Fold::X(5)), Ok(16));
+    // square outline is symmetric across the diagonal: only its 2 corners on the line stay single
+    assert_eq!(paper.fold(&Fold::Diagonal(0)), Ok(9));
+    // folds mirroring dots out of range leave the paper unfolded
+    assert!(paper.fold(&Fold::X(-2_000_000_000)).is_err());
+    assert_eq!(paper.dots.len(), 9);
+}
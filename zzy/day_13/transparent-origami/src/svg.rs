@@ -0,0 +1,205 @@
+//!
+//! Anti Helmet
+//! Advent of Code
+//! Day 13: Transparent Origami
+//! SVG Export
+//!
+
+use crate::paper::{Dot, Fold};
+use std::io::{self, Write};
+
+#[cfg(test)]
+mod tests;
+
+/// Size in pixels of the square drawn for each dot.
+const CELL_SIZE: i32 = 10;
+/// Spacing in pixels around & between panels.
+const MARGIN: i32 = 10;
+/// Height in pixels reserved above each panel for its title.
+const TITLE_HEIGHT: i32 = 20;
+/// Approximate width in pixels of each character of a panel's title.
+const CHAR_WIDTH: i32 = 9;
+
+/// Bounding box of the paper drawn in a panel in cells, where the dot at (x, y)
+/// fills the cell from (x, y) to (x + 1, y + 1).
+#[derive(Debug, PartialEq, Eq)]
+struct Bounds {
+    min_x: i32,
+    min_y: i32,
+    max_x: i32,
+    max_y: i32,
+}
+impl Bounds {
+    /// Compute bounds enclosing the given dots & the line of the given axis fold.
+    fn new(dots: &[Dot], fold: Option<&Fold>) -> Self {
+        let mut bounds = Self {
+            min_x: dots.iter().map(|dot| dot.x).min().unwrap_or(0),
+            min_y: dots.iter().map(|dot| dot.y).min().unwrap_or(0),
+            max_x: dots.iter().map(|dot| dot.x + 1).max().unwrap_or(1),
+            max_y: dots.iter().map(|dot| dot.y + 1).max().unwrap_or(1),
+        };
+        match fold {
+            Some(&Fold::X(location)) => {
+                bounds.min_x = bounds.min_x.min(location);
+                bounds.max_x = bounds.max_x.max(location + 1);
+            }
+            Some(&Fold::Y(location)) => {
+                bounds.min_y = bounds.min_y.min(location);
+                bounds.max_y = bounds.max_y.max(location + 1);
+            }
+            _ => (),
+        }
+        bounds
+    }
+
+    /// Width of the bounds in pixels.
+    fn width(&self) -> i32 {
+        (self.max_x - self.min_x) * CELL_SIZE
+    }
+
+    /// Height of the bounds in pixels.
+    fn height(&self) -> i32 {
+        (self.max_y - self.min_y) * CELL_SIZE
+    }
+}
+
+/// Find the end points of the given fold's line clipped to the given bounds in cells.
+/// The line passes through the centre of the cells of the dots that lie on it.
+/// Returns None if the line does not cross the bounds.
+fn fold_line(fold: &Fold, bounds: &Bounds) -> Option<((f64, f64), (f64, f64))> {
+    let (min_x, min_y) = (bounds.min_x as f64, bounds.min_y as f64);
+    let (max_x, max_y) = (bounds.max_x as f64, bounds.max_y as f64);
+    // diagonal lines given as y = slope * x + intercept
+    let (slope, intercept) = match *fold {
+        Fold::X(location) => {
+            let x = location as f64 + 0.5;
+            return Some(((x, min_y), (x, max_y)));
+        }
+        Fold::Y(location) => {
+            let y = location as f64 + 0.5;
+            return Some(((min_x, y), (max_x, y)));
+        }
+        Fold::Diagonal(offset) => (1.0, offset as f64),
+        Fold::AntiDiagonal(offset) => (-1.0, offset as f64 + 1.0),
+    };
+    // clip line to the range of x where it stays within the bounds' y range
+    let (y_begin_x, y_end_x) = ((min_y - intercept) * slope, (max_y - intercept) * slope);
+    let begin_x = min_x.max(y_begin_x.min(y_end_x));
+    let end_x = max_x.min(y_begin_x.max(y_end_x));
+    if begin_x >= end_x {
+        return None;
+    }
+    Some((
+        (begin_x, slope * begin_x + intercept),
+        (end_x, slope * end_x + intercept),
+    ))
+}
+
+/// Stage of a fold sequence: the dots visible on the paper after a fold.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stage {
+    /// Fold applied to reach this stage, None for the paper before any folds.
+    pub fold: Option<Fold>,
+    /// Dots visible on the paper at this stage.
+    pub dots: Vec<Dot>,
+}
+
+/// Panel drawing a stage of the fold sequence.
+struct Panel<'a> {
+    title: String,
+    /// Dots visible at the stage, sorted top to bottom, left to right.
+    dots: Vec<Dot>,
+    /// Fold applied next to the paper, drawn as a line over the dots.
+    next_fold: Option<&'a Fold>,
+    bounds: Bounds,
+}
+
+/// Write the given stages of a fold sequence, starting from the paper before any folds,
+/// as a single SVG document with a panel for each stage stacked top to bottom.
+/// Each panel draws the dots visible at that stage & the line of the fold applied next.
+pub fn write_svg<W: Write>(out: &mut W, stages: &[Stage]) -> io::Result<()> {
+    let panels: Vec<_> = stages
+        .iter()
+        .enumerate()
+        .map(|(i_stage, stage)| {
+            let title = match &stage.fold {
+                None => "Initial".to_string(),
+                Some(fold) => format!("After {}", fold),
+            };
+            let mut dots = stage.dots.clone();
+            dots.sort_by_key(|dot| (dot.y, dot.x));
+            let next_fold = stages
+                .get(i_stage + 1)
+                .and_then(|next_stage| next_stage.fold.as_ref());
+            Panel {
+                title: format!("{}: {} dots visible", title, dots.len()),
+                bounds: Bounds::new(&dots, next_fold),
+                dots,
+                next_fold,
+            }
+        })
+        .collect();
+
+    let width = panels
+        .iter()
+        // fit both the paper & the title of the widest panel
+        .map(|panel| {
+            panel
+                .bounds
+                .width()
+                .max(panel.title.len() as i32 * CHAR_WIDTH)
+        })
+        .max()
+        .unwrap_or(0)
+        + 2 * MARGIN;
+    let height = panels
+        .iter()
+        .map(|panel| TITLE_HEIGHT + panel.bounds.height() + MARGIN)
+        .sum::<i32>()
+        + MARGIN;
+    writeln!(
+        out,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" \
+         font-family=\"monospace\" font-size=\"14\">",
+        width, height
+    )?;
+    let mut top = MARGIN;
+    for panel in panels.iter() {
+        let bounds = &panel.bounds;
+        writeln!(out, "  <g transform=\"translate({},{})\">", MARGIN, top)?;
+        writeln!(out, "    <text x=\"0\" y=\"14\">{}</text>", panel.title)?;
+        writeln!(
+            out,
+            "    <rect x=\"0\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"none\" stroke=\"#ccc\"/>",
+            TITLE_HEIGHT,
+            bounds.width(),
+            bounds.height()
+        )?;
+        for dot in panel.dots.iter() {
+            writeln!(
+                out,
+                "    <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\"/>",
+                (dot.x - bounds.min_x) * CELL_SIZE,
+                TITLE_HEIGHT + (dot.y - bounds.min_y) * CELL_SIZE,
+                CELL_SIZE,
+                CELL_SIZE
+            )?;
+        }
+        if let Some(((x1, y1), (x2, y2))) = panel.next_fold.and_then(|f| fold_line(f, bounds)) {
+            // convert line end points from cells to pixels relative to the panel
+            let to_px = |cells: f64, min: i32| (cells - min as f64) * CELL_SIZE as f64;
+            writeln!(
+                out,
+                "    <line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" \
+                 stroke=\"red\" stroke-dasharray=\"4\"/>",
+                to_px(x1, bounds.min_x),
+                TITLE_HEIGHT as f64 + to_px(y1, bounds.min_y),
+                to_px(x2, bounds.min_x),
+                TITLE_HEIGHT as f64 + to_px(y2, bounds.min_y)
+            )?;
+        }
+        writeln!(out, "  </g>")?;
+        top += TITLE_HEIGHT + bounds.height() + MARGIN;
+    }
+    writeln!(out, "</svg>")
+}
@@ -0,0 +1,94 @@
+//!
+//! Anti Helmet
+//! Advent of Code
+//! Day 13: Transparent Origami
+//! Unit Tests
+//!
+
+use super::*;
+
+fn bounds(min_x: i32, min_y: i32, max_x: i32, max_y: i32) -> Bounds {
+    Bounds {
+        min_x,
+        min_y,
+        max_x,
+        max_y,
+    }
+}
+
+#[test]
+fn test_bounds() {
+    let dots = [Dot { x: 1, y: 2 }, Dot { x: 3, y: 0 }];
+    assert_eq!(Bounds::new(&dots, None), bounds(1, 0, 4, 3));
+    // bounds extend to fit the line of axis folds
+    assert_eq!(Bounds::new(&dots, Some(&Fold::X(5))), bounds(1, 0, 6, 3));
+    assert_eq!(Bounds::new(&dots, Some(&Fold::Y(-1))), bounds(1, -1, 4, 3));
+    assert_eq!(
+        Bounds::new(&dots, Some(&Fold::Diagonal(9))),
+        bounds(1, 0, 4, 3)
+    );
+}
+
+#[test]
+fn test_fold_line() {
+    let bounds = bounds(0, 0, 4, 4);
+    assert_eq!(
+        fold_line(&Fold::X(2), &bounds),
+        Some(((2.5, 0.0), (2.5, 4.0)))
+    );
+    assert_eq!(
+        fold_line(&Fold::Y(1), &bounds),
+        Some(((0.0, 1.5), (4.0, 1.5)))
+    );
+    assert_eq!(
+        fold_line(&Fold::Diagonal(0), &bounds),
+        Some(((0.0, 0.0), (4.0, 4.0)))
+    );
+    assert_eq!(
+        fold_line(&Fold::Diagonal(2), &bounds),
+        Some(((0.0, 2.0), (2.0, 4.0)))
+    );
+    assert_eq!(
+        fold_line(&Fold::AntiDiagonal(3), &bounds),
+        Some(((0.0, 4.0), (4.0, 0.0)))
+    );
+    // lines that miss the bounds are not drawn
+    assert_eq!(fold_line(&Fold::Diagonal(5), &bounds), None);
+    assert_eq!(fold_line(&Fold::AntiDiagonal(-2), &bounds), None);
+}
+
+#[test]
+fn test_write_svg() {
+    let stages = [
+        Stage {
+            fold: None,
+            dots: vec![Dot { x: 2, y: 1 }, Dot { x: 0, y: 0 }],
+        },
+        Stage {
+            fold: Some(Fold::X(1)),
+            dots: vec![Dot { x: 0, y: 0 }, Dot { x: 0, y: 1 }],
+        },
+    ];
+    let mut out = Vec::new();
+    write_svg(&mut out, &stages).unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"344\" height=\"110\" \
+         font-family=\"monospace\" font-size=\"14\">
+  <g transform=\"translate(10,10)\">
+    <text x=\"0\" y=\"14\">Initial: 2 dots visible</text>
+    <rect x=\"0\" y=\"20\" width=\"30\" height=\"20\" fill=\"none\" stroke=\"#ccc\"/>
+    <rect x=\"0\" y=\"20\" width=\"10\" height=\"10\"/>
+    <rect x=\"20\" y=\"30\" width=\"10\" height=\"10\"/>
+    <line x1=\"15\" y1=\"20\" x2=\"15\" y2=\"40\" stroke=\"red\" stroke-dasharray=\"4\"/>
+  </g>
+  <g transform=\"translate(10,60)\">
+    <text x=\"0\" y=\"14\">After fold along x=1: 2 dots visible</text>
+    <rect x=\"0\" y=\"20\" width=\"10\" height=\"20\" fill=\"none\" stroke=\"#ccc\"/>
+    <rect x=\"0\" y=\"20\" width=\"10\" height=\"10\"/>
+    <rect x=\"0\" y=\"30\" width=\"10\" height=\"10\"/>
+  </g>
+</svg>
+"
+    );
+}
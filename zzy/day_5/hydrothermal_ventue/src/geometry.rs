@@ -0,0 +1,189 @@
+//!
+//! Anti Helmet
+//! Advent of Code
+//! Day 5: Hydrothermal Venture
+//! Geometry
+//!
+
+#[cfg(test)]
+mod tests;
+
+/// Represents a point / vector on 2D x, y plane
+#[derive(Debug, PartialEq, Hash, Eq, Copy, Clone)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// Classifies a line segment by its slope.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum LineClass {
+    Horizontal,
+    Vertical,
+    /// Line at exactly 45 degrees to the axes.
+    Diagonal,
+    /// Line at any other angle.
+    Oblique,
+}
+impl LineClass {
+    /// Parse a line class from its lowercase name.
+    /// Returns None if the name does not match any line class.
+    pub fn parse(name: &str) -> Option<Self> {
+        use LineClass::*;
+        match name {
+            "horizontal" => Some(Horizontal),
+            "vertical" => Some(Vertical),
+            "diagonal" => Some(Diagonal),
+            "oblique" => Some(Oblique),
+            _ => None,
+        }
+    }
+}
+
+/// Defines how a line segment is rasterised into points.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Raster {
+    /// Only the exact integer lattice points that lie on the line.
+    Lattice,
+    /// Approximate the line with one point per step along its major axis.
+    Bresenham,
+}
+
+/// Represents a line segments on 2D plane
+#[derive(Debug, Copy, Clone)]
+pub struct Line {
+    pub begin: Point,
+    pub end: Point,
+}
+impl Line {
+    /// Classify this line by its slope.
+    /// A degenerate line where begin and end coincide is classified as horizontal.
+    pub fn class(&self) -> LineClass {
+        let (dx, dy) = (self.end.x - self.begin.x, self.end.y - self.begin.y);
+        if dy == 0 {
+            LineClass::Horizontal
+        } else if dx == 0 {
+            LineClass::Vertical
+        } else if dx.abs() == dy.abs() {
+            LineClass::Diagonal
+        } else {
+            LineClass::Oblique
+        }
+    }
+
+    /// Iterate over the points of this line using the given rasterisation method.
+    pub fn iter(&self, raster: Raster) -> Box<dyn Iterator<Item = Point>> {
+        match raster {
+            Raster::Lattice => Box::new(self.lattice_points()),
+            Raster::Bresenham => Box::new(self.bresenham_points()),
+        }
+    }
+
+    /// Iterate over the integer lattice points that lie exactly on this line.
+    /// Steps from begin to end by the line's delta divided by the gcd of its components,
+    /// which is the smallest step that lands on another lattice point.
+    pub fn lattice_points(&self) -> PointIterator {
+        let (dx, dy) = (self.end.x - self.begin.x, self.end.y - self.begin.y);
+        let n_steps = gcd(dx.abs(), dy.abs());
+        let delta = if n_steps == 0 {
+            Point { x: 0, y: 0 }
+        } else {
+            Point {
+                x: dx / n_steps,
+                y: dy / n_steps,
+            }
+        };
+        PointIterator {
+            current: self.begin,
+            delta,
+            n_left: n_steps + 1,
+        }
+    }
+
+    /// Iterate over the points approximating this line using Bresenham's algorithm.
+    pub fn bresenham_points(&self) -> BresenhamIterator {
+        BresenhamIterator {
+            current: self.begin,
+            end: self.end,
+            step: Point {
+                x: (self.end.x - self.begin.x).signum(),
+                y: (self.end.y - self.begin.y).signum(),
+            },
+            delta: Point {
+                x: (self.end.x - self.begin.x).abs(),
+                y: -(self.end.y - self.begin.y).abs(),
+            },
+            error: (self.end.x - self.begin.x).abs() - (self.end.y - self.begin.y).abs(),
+            done: false,
+        }
+    }
+}
+
+/// Compute the greatest common divisor of the given non-negative integers.
+fn gcd(a: i32, b: i32) -> i32 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Iterates over evenly spaced points on a line
+pub struct PointIterator {
+    current: Point,
+    delta: Point,
+    n_left: i32,
+}
+impl Iterator for PointIterator {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.n_left <= 0 {
+            None
+        } else {
+            let pt = Some(self.current);
+            self.current.x += self.delta.x;
+            self.current.y += self.delta.y;
+            self.n_left -= 1;
+            pt
+        }
+    }
+}
+
+/// Iterates over the points of a line rasterised by Bresenham's algorithm.
+pub struct BresenhamIterator {
+    current: Point,
+    end: Point,
+    /// Direction to step in on each axis.
+    step: Point,
+    /// Absolute x delta and negated absolute y delta of the line.
+    delta: Point,
+    /// Accumulated error between the rasterised and ideal line.
+    error: i32,
+    done: bool,
+}
+impl Iterator for BresenhamIterator {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let pt = self.current;
+        if pt == self.end {
+            self.done = true;
+        } else {
+            // step along each axis whose error term has exceeded half a pixel
+            let error2 = 2 * self.error;
+            if error2 >= self.delta.y {
+                self.error += self.delta.y;
+                self.current.x += self.step.x;
+            }
+            if error2 <= self.delta.x {
+                self.error += self.delta.x;
+                self.current.y += self.step.y;
+            }
+        }
+        Some(pt)
+    }
+}
@@ -0,0 +1,77 @@
+//!
+//! Anti Helmet
+//! Advent of Code
+//! Day 5: Hydrothermal Venture
+//! Unit Tests
+//!
+
+use super::*;
+
+fn line(x1: i32, y1: i32, x2: i32, y2: i32) -> Line {
+    Line {
+        begin: Point { x: x1, y: y1 },
+        end: Point { x: x2, y: y2 },
+    }
+}
+
+fn points(coords: &[(i32, i32)]) -> Vec<Point> {
+    coords.iter().map(|&(x, y)| Point { x, y }).collect()
+}
+
+#[test]
+fn test_line_class() {
+    use LineClass::*;
+    assert_eq!(line(0, 9, 5, 9).class(), Horizontal);
+    assert_eq!(line(7, 0, 7, 4).class(), Vertical);
+    assert_eq!(line(8, 0, 0, 8).class(), Diagonal);
+    assert_eq!(line(0, 0, 6, 4).class(), Oblique);
+    assert_eq!(line(3, 3, 3, 3).class(), Horizontal);
+}
+
+#[test]
+fn test_lattice_points() {
+    assert_eq!(
+        line(1, 1, 1, 3).lattice_points().collect::<Vec<_>>(),
+        points(&[(1, 1), (1, 2), (1, 3)])
+    );
+    assert_eq!(
+        line(9, 7, 7, 9).lattice_points().collect::<Vec<_>>(),
+        points(&[(9, 7), (8, 8), (7, 9)])
+    );
+    assert_eq!(
+        line(0, 0, 6, 4).lattice_points().collect::<Vec<_>>(),
+        points(&[(0, 0), (3, 2), (6, 4)])
+    );
+    assert_eq!(
+        line(5, 0, 0, 3).lattice_points().collect::<Vec<_>>(),
+        points(&[(5, 0), (0, 3)])
+    );
+    assert_eq!(
+        line(2, 2, 2, 2).lattice_points().collect::<Vec<_>>(),
+        points(&[(2, 2)])
+    );
+}
+
+#[test]
+fn test_bresenham_points() {
+    // bresenham should agree with lattice points on axis aligned and diagonal lines
+    for test_line in [line(0, 9, 5, 9), line(7, 4, 7, 0), line(8, 0, 0, 8)].iter() {
+        assert_eq!(
+            test_line.bresenham_points().collect::<Vec<_>>(),
+            test_line.lattice_points().collect::<Vec<_>>()
+        );
+    }
+
+    assert_eq!(
+        line(0, 0, 4, 2).bresenham_points().collect::<Vec<_>>(),
+        points(&[(0, 0), (1, 1), (2, 1), (3, 2), (4, 2)])
+    );
+    assert_eq!(
+        line(4, 2, 0, 0).bresenham_points().count(),
+        line(0, 0, 4, 2).bresenham_points().count()
+    );
+    assert_eq!(
+        line(2, 2, 2, 2).bresenham_points().collect::<Vec<_>>(),
+        points(&[(2, 2)])
+    );
+}
@@ -6,59 +6,81 @@
 
 use regex::Regex;
 use std::collections::HashMap;
-use std::io::{stdin, Read};
+use std::env;
+use std::fs::File;
+use std::io::{stdin, stdout, BufWriter, Read};
 
-/// Represents a point / vector on 2D x, y plane
-#[derive(PartialEq, Hash, Eq, Copy, Clone )]
-struct Point {
-    x: i32,
-    y: i32,
-}
+mod geometry;
+mod render;
+use geometry::*;
+use render::OverlapGrid;
 
-/// Represents a line segments on 2D plane
-struct Line {
-    begin: Point,
-    end: Point,
+/// Options controlling which lines are counted, how they are rasterised
+/// and where the resulting vent density map is exported.
+struct Options {
+    raster: Raster,
+    line_classes: Vec<LineClass>,
+    pgm_path: Option<String>,
+    ppm_path: Option<String>,
+    heatmap_width: Option<usize>,
 }
-impl Line {
-    fn iter(&self) -> PointIterator {
-        let delta = Point {
-            x: (self.end.x - self.begin.x).signum(),
-            y: (self.end.y - self.begin.y).signum(),
+impl Options {
+    /// Parse options from the given command line arguments:
+    /// - `--raster=lattice|bresenham` selects the rasterisation method (default: lattice).
+    /// - `--lines=CLASS,...` only counts lines of the given classes (default: all).
+    ///   Classes are horizontal, vertical, diagonal, oblique, or axis for both
+    ///   horizontal and vertical.
+    /// - `--pgm=PATH` exports the vent density map as a portable graymap.
+    /// - `--ppm=PATH` exports the vent density map as a portable pixmap.
+    /// - `--heatmap=WIDTH` prints the vent density map as an ASCII heatmap
+    ///   scaled to fit within the given no. of columns.
+    fn parse<T: Iterator<Item = String>>(args: T) -> Self {
+        use LineClass::*;
+        let mut options = Options {
+            raster: Raster::Lattice,
+            line_classes: vec![Horizontal, Vertical, Diagonal, Oblique],
+            pgm_path: None,
+            ppm_path: None,
+            heatmap_width: None,
         };
-        PointIterator {
-            current: self.begin,
-            delta: delta,
-            end: Point {
-                x: self.end.x + delta.x,
-                y: self.end.y + delta.y,
-            },
-        }
-    }
-}
-
-/// Iterates over all points on a line
-struct PointIterator {
-    current: Point,
-    delta: Point,
-    end: Point,
-}
-impl Iterator for PointIterator {
-    type Item = Point;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.current == self.end {
-            None
-        } else {
-            let pt = Some(self.current);
-            self.current.x += self.delta.x;
-            self.current.y += self.delta.y;
-            pt
+        for arg in args {
+            let (flag, value) = arg
+                .split_once('=')
+                .expect("Expected options in the format --flag=value");
+            match flag {
+                "--raster" => {
+                    options.raster = match value {
+                        "lattice" => Raster::Lattice,
+                        "bresenham" => Raster::Bresenham,
+                        _ => panic!("Unsupported rasterisation method: {}", value),
+                    }
+                }
+                "--lines" => {
+                    options.line_classes = value
+                        .split(',')
+                        .flat_map(|name| match name {
+                            "axis" => vec![Horizontal, Vertical],
+                            _ => vec![LineClass::parse(name)
+                                .unwrap_or_else(|| panic!("Unsupported line class: {}", name))],
+                        })
+                        .collect();
+                }
+                "--pgm" => options.pgm_path = Some(value.to_string()),
+                "--ppm" => options.ppm_path = Some(value.to_string()),
+                "--heatmap" => {
+                    options.heatmap_width =
+                        Some(value.parse().expect("Failed to parse heatmap width as int"))
+                }
+                _ => panic!("Unsupported option: {}", flag),
+            }
         }
+        options
     }
 }
 
 fn main() {
+    let options = Options::parse(env::args().skip(1));
+
     // read lines from stdin
     let mut input = String::new();
     stdin()
@@ -69,7 +91,7 @@ fn main() {
     let line_re = Regex::new(r"(\d+),(\d+) -> (\d+),(\d+)").unwrap();
     let lines: Vec<_> = input
         .trim_end()
-        .split("\n")
+        .split('\n')
         .map(|line| {
             let captures = line_re.captures(line).expect("Failed to parse line");
 
@@ -85,16 +107,14 @@ fn main() {
                 },
             )
         })
-        .map(|(begin, end)| Line {
-            begin: begin,
-            end: end,
-        })
+        .map(|(begin, end)| Line { begin, end })
+        .filter(|line| options.line_classes.contains(&line.class()))
         .collect();
 
     // walk each line and mark each point it covers inthe intersect_counts map
-    let mut intersect_counts = HashMap::new();
+    let mut intersect_counts: HashMap<Point, u32> = HashMap::new();
     for line in lines {
-        for pt in line.iter() {
+        for pt in line.iter(options.raster) {
             let count = intersect_counts.entry(pt).or_insert(0);
             *count += 1;
         }
@@ -103,5 +123,26 @@ fn main() {
         .values()
         .filter(|&&count| count >= 2)
         .count();
-    println!("No. of points 2 or more intersections: {}", n_intersect_pts)
+    println!("No. of points 2 or more intersections: {}", n_intersect_pts);
+
+    // export vent density map if requested: only build the dense grid when exporting,
+    // as it covers the whole bounding box of the vents
+    let is_exporting =
+        options.pgm_path.is_some() || options.ppm_path.is_some() || options.heatmap_width.is_some();
+    if !is_exporting {
+        return;
+    }
+    let grid = OverlapGrid::new(&intersect_counts);
+    if let Some(path) = options.pgm_path {
+        let mut file = BufWriter::new(File::create(&path).expect("Failed to create PGM file"));
+        grid.write_pgm(&mut file).expect("Failed to write PGM file");
+    }
+    if let Some(path) = options.ppm_path {
+        let mut file = BufWriter::new(File::create(&path).expect("Failed to create PPM file"));
+        grid.write_ppm(&mut file).expect("Failed to write PPM file");
+    }
+    if let Some(width) = options.heatmap_width {
+        grid.write_heatmap(&mut stdout(), width)
+            .expect("Failed to write heatmap to stdout");
+    }
 }
@@ -0,0 +1,156 @@
+//!
+//! Anti Helmet
+//! Advent of Code
+//! Day 5: Hydrothermal Venture
+//! Rendering
+//!
+
+use crate::geometry::Point;
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+#[cfg(test)]
+mod tests;
+
+/// Characters used to draw the ASCII heatmap, from least to most dense.
+const HEATMAP_RAMP: &[u8] = b" .:-=+*#%@";
+
+/// Dense grid of vent overlap counts covering the bounding box of all vent points.
+pub struct OverlapGrid {
+    /// Point at the top left corner of the grid.
+    origin: Point,
+    width: usize,
+    height: usize,
+    counts: Vec<u32>,
+}
+impl OverlapGrid {
+    /// Build a overlap grid from the given map of point to overlap count.
+    pub fn new(intersect_counts: &HashMap<Point, u32>) -> Self {
+        if intersect_counts.is_empty() {
+            return Self {
+                origin: Point { x: 0, y: 0 },
+                width: 0,
+                height: 0,
+                counts: Vec::new(),
+            };
+        }
+        let min_x = intersect_counts.keys().map(|pt| pt.x).min().unwrap();
+        let max_x = intersect_counts.keys().map(|pt| pt.x).max().unwrap();
+        let min_y = intersect_counts.keys().map(|pt| pt.y).min().unwrap();
+        let max_y = intersect_counts.keys().map(|pt| pt.y).max().unwrap();
+
+        let width = (max_x - min_x) as usize + 1;
+        let height = (max_y - min_y) as usize + 1;
+        let mut counts = vec![0; width * height];
+        for (pt, &count) in intersect_counts {
+            counts[(pt.y - min_y) as usize * width + (pt.x - min_x) as usize] = count;
+        }
+        Self {
+            origin: Point { x: min_x, y: min_y },
+            width,
+            height,
+            counts,
+        }
+    }
+
+    /// Retrieve the overlap count at the given column and row of the grid.
+    pub fn get(&self, col: usize, row: usize) -> u32 {
+        self.counts[row * self.width + col]
+    }
+
+    /// Retrieve the highest overlap count in the grid.
+    pub fn max_count(&self) -> u32 {
+        self.counts.iter().copied().max().unwrap_or(0)
+    }
+
+    /// Write the grid as a plain portable graymap (PGM), one pixel per point.
+    /// Brighter pixels mark points with more overlapping vents.
+    pub fn write_pgm<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        let max_value = u32::max(self.max_count(), 1);
+        writeln!(out, "P2")?;
+        writeln!(out, "# origin {},{}", self.origin.x, self.origin.y)?;
+        writeln!(out, "{} {}", self.width, self.height)?;
+        writeln!(out, "{}", max_value)?;
+        for row in 0..self.height {
+            let values: Vec<_> = (0..self.width)
+                .map(|col| self.get(col, row).to_string())
+                .collect();
+            writeln!(out, "{}", values.join(" "))?;
+        }
+        Ok(())
+    }
+
+    /// Write the grid as a plain portable pixmap (PPM), one pixel per point.
+    /// Overlap counts are mapped onto a black-red-yellow-white heat colour ramp.
+    pub fn write_ppm<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        let max_count = self.max_count();
+        writeln!(out, "P3")?;
+        writeln!(out, "# origin {},{}", self.origin.x, self.origin.y)?;
+        writeln!(out, "{} {}", self.width, self.height)?;
+        writeln!(out, "255")?;
+        for row in 0..self.height {
+            let pixels: Vec<_> = (0..self.width)
+                .map(|col| {
+                    let (r, g, b) = heat_colour(self.get(col, row), max_count);
+                    format!("{} {} {}", r, g, b)
+                })
+                .collect();
+            writeln!(out, "{}", pixels.join("  "))?;
+        }
+        Ok(())
+    }
+
+    /// Write the grid as an ASCII heatmap at most max_width characters wide.
+    /// The grid is downscaled by the same factor on both axes, with each character
+    /// showing the highest overlap count in the block of points it covers.
+    pub fn write_heatmap<W: Write>(&self, out: &mut W, max_width: usize) -> io::Result<()> {
+        let max_count = self.max_count();
+        let max_width = usize::max(max_width, 1);
+        let scale = usize::max((self.width + max_width - 1) / max_width, 1);
+        for block_row in (0..self.height).step_by(scale) {
+            let line: String = (0..self.width)
+                .step_by(scale)
+                .map(|block_col| {
+                    let block_max = (block_row..usize::min(block_row + scale, self.height))
+                        .flat_map(|row| {
+                            (block_col..usize::min(block_col + scale, self.width))
+                                .map(move |col| (col, row))
+                        })
+                        .map(|(col, row)| self.get(col, row))
+                        .max()
+                        .unwrap_or(0);
+                    heatmap_char(block_max, max_count)
+                })
+                .collect();
+            writeln!(out, "{}", line.trim_end())?;
+        }
+        writeln!(
+            out,
+            "scale 1:{}, origin {},{}, max overlap {}",
+            scale, self.origin.x, self.origin.y, max_count
+        )
+    }
+}
+
+/// Map the given overlap count to a heatmap character relative to the max count.
+fn heatmap_char(count: u32, max_count: u32) -> char {
+    if count == 0 {
+        HEATMAP_RAMP[0] as char
+    } else {
+        // reserve the blank first character for points with no vents
+        let n_levels = HEATMAP_RAMP.len() - 1;
+        let level = (count as usize * n_levels + max_count as usize - 1) / max_count as usize;
+        HEATMAP_RAMP[usize::min(level, n_levels)] as char
+    }
+}
+
+/// Map the given overlap count to a RGB heat colour relative to the max count.
+fn heat_colour(count: u32, max_count: u32) -> (u8, u8, u8) {
+    if count == 0 {
+        return (0, 0, 0);
+    }
+    // scale intensity so that the max count maps to the top of the ramp
+    let intensity = count as f64 / max_count as f64 * 3.0;
+    let channel = |offset: f64| ((intensity - offset).clamp(0.0, 1.0) * 255.0).round() as u8;
+    (channel(0.0), channel(1.0), channel(2.0))
+}
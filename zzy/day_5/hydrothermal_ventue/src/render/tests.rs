@@ -0,0 +1,81 @@
+//!
+//! Anti Helmet
+//! Advent of Code
+//! Day 5: Hydrothermal Venture
+//! Unit Tests
+//!
+
+use super::*;
+
+/// Build a overlap grid from the given (x, y, count) entries.
+fn grid(entries: &[(i32, i32, u32)]) -> OverlapGrid {
+    let intersect_counts: HashMap<_, _> = entries
+        .iter()
+        .map(|&(x, y, count)| (Point { x, y }, count))
+        .collect();
+    OverlapGrid::new(&intersect_counts)
+}
+
+fn render(write: impl FnOnce(&mut Vec<u8>) -> io::Result<()>) -> String {
+    let mut out = Vec::new();
+    write(&mut out).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn test_overlap_grid_new() {
+    let grid = grid(&[(2, 1, 1), (4, 2, 3)]);
+    assert_eq!((grid.width, grid.height), (3, 2));
+    assert_eq!(grid.get(0, 0), 1);
+    assert_eq!(grid.get(1, 0), 0);
+    assert_eq!(grid.get(2, 1), 3);
+    assert_eq!(grid.max_count(), 3);
+}
+
+#[test]
+fn test_write_pgm() {
+    let empty = grid(&[]);
+    let grid = grid(&[(2, 1, 1), (4, 2, 3)]);
+    assert_eq!(
+        render(|out| grid.write_pgm(out)),
+        "P2\n# origin 2,1\n3 2\n3\n1 0 0\n0 0 3\n"
+    );
+    // max value is kept positive for grids without any vents
+    assert_eq!(
+        render(|out| empty.write_pgm(out)),
+        "P2\n# origin 0,0\n0 0\n1\n"
+    );
+}
+
+#[test]
+fn test_write_ppm() {
+    let grid = grid(&[(0, 0, 1), (1, 0, 2), (2, 0, 3), (0, 1, 0)]);
+    assert_eq!(
+        render(|out| grid.write_ppm(out)),
+        "P3\n# origin 0,0\n3 2\n255\n\
+         255 0 0  255 255 0  255 255 255\n\
+         0 0 0  0 0 0  0 0 0\n"
+    );
+}
+
+#[test]
+fn test_heat_colour() {
+    assert_eq!(heat_colour(0, 6), (0, 0, 0));
+    assert_eq!(heat_colour(1, 6), (128, 0, 0));
+    assert_eq!(heat_colour(3, 6), (255, 128, 0));
+    assert_eq!(heat_colour(6, 6), (255, 255, 255));
+}
+
+#[test]
+fn test_write_heatmap() {
+    let grid = grid(&[(0, 0, 1), (1, 0, 9), (3, 1, 3)]);
+    assert_eq!(
+        render(|out| grid.write_heatmap(out, 10)),
+        ".@\n   -\nscale 1:1, origin 0,0, max overlap 9\n"
+    );
+    // downscaling keeps the highest count in each block of points
+    assert_eq!(
+        render(|out| grid.write_heatmap(out, 2)),
+        "@-\nscale 1:2, origin 0,0, max overlap 9\n"
+    );
+}
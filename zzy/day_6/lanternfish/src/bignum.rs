@@ -0,0 +1,113 @@
+//!
+//! Anti Helmet
+//! Advent of Code
+//! Day 6: Lanternfish
+//! Arbitrary Precision Integers
+//!
+
+use std::fmt;
+
+/// Arbitrary precision unsigned integer stored as base 2^32 limbs,
+/// least significant limb first with no trailing zero limbs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BigUint {
+    limbs: Vec<u32>,
+}
+impl BigUint {
+    pub fn zero() -> Self {
+        Self { limbs: Vec::new() }
+    }
+
+    pub fn from_u64(value: u64) -> Self {
+        let mut number = Self {
+            limbs: vec![value as u32, (value >> 32) as u32],
+        };
+        number.normalize();
+        number
+    }
+
+    /// Remove trailing zero limbs so that each number has exactly one representation.
+    fn normalize(&mut self) {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// Compute the sum of this and the other given number.
+    pub fn add(&self, other: &Self) -> Self {
+        let (long, short) = if self.limbs.len() >= other.limbs.len() {
+            (&self.limbs, &other.limbs)
+        } else {
+            (&other.limbs, &self.limbs)
+        };
+        let mut limbs = Vec::with_capacity(long.len() + 1);
+        let mut carry = 0u64;
+        for (i, &limb) in long.iter().enumerate() {
+            let sum = limb as u64 + *short.get(i).unwrap_or(&0) as u64 + carry;
+            limbs.push(sum as u32);
+            carry = sum >> 32;
+        }
+        limbs.push(carry as u32);
+
+        let mut number = Self { limbs };
+        number.normalize();
+        number
+    }
+
+    /// Compute the product of this and the other given number by long multiplication.
+    pub fn mul(&self, other: &Self) -> Self {
+        if self.is_zero() || other.is_zero() {
+            return Self::zero();
+        }
+        let mut limbs = vec![0u32; self.limbs.len() + other.limbs.len()];
+        for (i, &a) in self.limbs.iter().enumerate() {
+            let mut carry = 0u64;
+            for (j, &b) in other.limbs.iter().enumerate() {
+                let product = a as u64 * b as u64 + limbs[i + j] as u64 + carry;
+                limbs[i + j] = product as u32;
+                carry = product >> 32;
+            }
+            limbs[i + other.limbs.len()] = carry as u32;
+        }
+
+        let mut number = Self { limbs };
+        number.normalize();
+        number
+    }
+
+    /// Divide this number in place by the given small divisor.
+    /// Returns the remainder of the division.
+    fn div_rem_small(&mut self, divisor: u32) -> u32 {
+        let mut remainder = 0u64;
+        for limb in self.limbs.iter_mut().rev() {
+            let dividend = (remainder << 32) | *limb as u64;
+            *limb = (dividend / divisor as u64) as u32;
+            remainder = dividend % divisor as u64;
+        }
+        self.normalize();
+        remainder as u32
+    }
+}
+impl fmt::Display for BigUint {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_zero() {
+            return write!(out, "0");
+        }
+        // peel off 9 decimal digits at a time, least significant chunk first
+        const CHUNK: u32 = 1_000_000_000;
+        let mut number = self.clone();
+        let mut chunks = Vec::new();
+        while !number.is_zero() {
+            chunks.push(number.div_rem_small(CHUNK));
+        }
+        write!(out, "{}", chunks.pop().unwrap())?;
+        for chunk in chunks.iter().rev() {
+            write!(out, "{:09}", chunk)?;
+        }
+        Ok(())
+    }
+}
@@ -5,36 +5,130 @@
 //!
 
 use std::collections::HashMap;
+use std::env;
+use std::fmt;
 use std::io::{stdin, Read};
 
-/// Defines the delay in days before a lanternfish can reproduce
-const REPRODUCE_DELAY_DAYS: u8 = 6;
+mod bignum;
+mod matrix;
+mod species;
+use matrix::{Exact, Matrix, Modulo, Ring};
+use species::Species;
 
-/// Models a lanternfish that reproduces on a 7 day cycle.
-#[derive(PartialEq, Eq, Hash)]
-enum Fish {
-    /// Incubating state tracks no. of days left before the fish reproduces.
+#[cfg(test)]
+mod tests;
+
+/// Lifecycle stage of a lanternfish.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+enum Stage {
+    /// Incubating stage tracks no. of days left before the fish reproduces.
     Incubating(u8),
     Reproducing,
 }
+impl Stage {
+    /// Stage of a fish that reproduces in the given no. of days.
+    fn after(n_days: u8) -> Self {
+        match n_days {
+            0 => Stage::Reproducing,
+            n_days => Stage::Incubating(n_days),
+        }
+    }
 
-impl Fish {
-    /// Advance the lanternfish model by one day.
-    /// Returns the next iteration of the current fish, and the new fish spawned,
-    /// if any together as a vector.
-    fn simulate(&self) -> Vec<Fish> {
-        use Fish::*;
+    /// Index of this stage among the stages of a species' lifecycle:
+    /// the no. of days left before the fish reproduces.
+    fn index(&self) -> usize {
         match self {
-            Incubating(n_days) if *n_days <= 1 => vec![Reproducing],
-            Incubating(n_days) => vec![Incubating(n_days - 1)],
-            Reproducing => vec![
+            Stage::Reproducing => 0,
+            Stage::Incubating(n_days) => *n_days as usize,
+        }
+    }
+}
+
+/// Models a lanternfish of a configurable species.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+struct Fish {
+    stage: Stage,
+    /// Age of the fish in days. Only tracked for mortal species, otherwise always 0.
+    age: u32,
+}
+
+impl Fish {
+    /// Advance the lanternfish model of the given species by one day.
+    /// Returns the next iteration of the current fish if it survives, and the
+    /// new fishes spawned, if any together as a vector of fishes with the no. of
+    /// fishes each stands for, so that a litter is returned as a single newborn.
+    fn simulate(&self, species: &Species) -> Vec<(Fish, u64)> {
+        use Stage::*;
+        let mut fishes = Vec::new();
+        let stage = match self.stage {
+            Incubating(n_days) if n_days <= 1 => Reproducing,
+            Incubating(n_days) => Incubating(n_days - 1),
+            Reproducing => {
+                // new fish: spend newborn_delay more days incubating before reproducing.
+                let newborn = Fish {
+                    stage: Stage::after(species.newborn_cycle() - 1),
+                    age: 0,
+                };
+                fishes.push((newborn, species.litter_size as u64));
                 // reproduced: reset incubation cycle counter
-                Incubating(REPRODUCE_DELAY_DAYS),
-                // new fish: spend 2 more days incubating before reproducing.
-                Incubating(REPRODUCE_DELAY_DAYS + 2),
-            ],
+                Stage::after(species.reproduce_cycle - 1)
+            }
+        };
+
+        // fishes that reach the mortality age die after reproducing on their last day
+        match species.mortality_age {
+            Some(mortality_age) if self.age + 1 >= mortality_age => (),
+            Some(_) => fishes.insert(
+                0,
+                (
+                    Fish {
+                        stage,
+                        age: self.age + 1,
+                    },
+                    1,
+                ),
+            ),
+            None => fishes.insert(0, (Fish { stage, age: 0 }, 1)),
         }
+        fishes
     }
+
+    /// Index of this fish's state when fish counts are stored as a vector.
+    fn index(&self, species: &Species) -> usize {
+        self.stage.index() + species.n_stages() * self.age as usize
+    }
+
+    /// Retrieve the fish state stored at the given index of a fish count vector.
+    fn from_index(species: &Species, index: usize) -> Self {
+        Fish {
+            stage: Stage::after((index % species.n_stages()) as u8),
+            age: (index / species.n_stages()) as u32,
+        }
+    }
+}
+
+/// Parse a newly observed fish from its no. of days left before reproducing.
+/// Unlike simulating fishes one by one, the transition matrix only tracks the stages
+/// of the species' lifecycle, so timers past the newborn cycle are intentionally
+/// rejected instead of accepting any u8 timer.
+/// Returns an error message if the timer could not be parsed or exceeds the lifecycle.
+fn parse_fish(timer_str: &str, species: &Species) -> Result<Fish, String> {
+    let n_days: u8 = timer_str
+        .trim()
+        .parse()
+        .map_err(|_| format!("Failed to parse fish timer as unsigned int: {}", timer_str))?;
+    let fish = Fish {
+        stage: Stage::after(n_days),
+        age: 0,
+    };
+    if fish.stage.index() >= species.n_stages() {
+        return Err(format!(
+            "Fish timer {} exceeds the lifecycle of the species: expected at most {}",
+            n_days,
+            species.n_stages() - 1
+        ));
+    }
+    Ok(fish)
 }
 
 /// Count the no. of fishes in each unique fish state.
@@ -49,39 +143,180 @@ fn count_fishes<T: Iterator<Item = Fish>>(fishes: T) -> HashMap<Fish, usize> {
     fish_counts
 }
 
+/// No. of distinct fish states tracked for the given species.
+fn n_fish_states(species: &Species) -> usize {
+    species.n_stages() * species.n_ages()
+}
+
+/// Build the transition matrix of the lanternfish model for the given species,
+/// where the entry at row i, column j counts the fishes in state j that a fish
+/// in state i becomes after one day.
+fn transition_matrix<R: Ring>(ring: &R, species: &Species) -> Matrix<R::Elem> {
+    let n_states = n_fish_states(species);
+    let rows: Vec<_> = (0..n_states)
+        .map(|index| {
+            let mut row = vec![0; n_states];
+            for (new_fish, n_fishes) in Fish::from_index(species, index).simulate(species) {
+                row[new_fish.index(species)] += n_fishes;
+            }
+            row
+        })
+        .collect();
+    Matrix::from_rows(ring, &rows)
+}
+
+/// Convert the given fish counts into a vector of counts indexed by fish state.
+fn count_vector<R: Ring>(
+    ring: &R,
+    species: &Species,
+    fish_counts: &HashMap<Fish, usize>,
+) -> Vec<R::Elem> {
+    let mut counts = vec![0; n_fish_states(species)];
+    for (fish, &n_fishes) in fish_counts {
+        counts[fish.index(species)] += n_fishes as u64;
+    }
+    counts.into_iter().map(|count| ring.elem(count)).collect()
+}
+
+/// Sum the given vector of counts indexed by fish state into counts per lifecycle stage.
+fn stage_histogram<R: Ring>(ring: &R, species: &Species, counts: &[R::Elem]) -> Vec<R::Elem> {
+    let mut histogram = vec![ring.elem(0); species.n_stages()];
+    for (index, count) in counts.iter().enumerate() {
+        let stage = index % species.n_stages();
+        histogram[stage] = ring.add(&histogram[stage], count);
+    }
+    histogram
+}
+
+/// Compute the no. of lanternfish of the given species after the given no. of days
+/// from the given fish counts. Raises the transition matrix to the no. of days, so
+/// that the population after any no. of days is computed in O(log n_days) matrix
+/// multiplications.
+fn population<R: Ring>(
+    ring: &R,
+    species: &Species,
+    fish_counts: &HashMap<Fish, usize>,
+    n_days: u64,
+) -> R::Elem {
+    transition_matrix(ring, species)
+        .pow(ring, n_days)
+        .left_mul(ring, &count_vector(ring, species, fish_counts))
+        .iter()
+        .fold(ring.elem(0), |total, count| ring.add(&total, count))
+}
+
+/// Print the no. of lanternfish of the given species in each lifecycle stage for
+/// each day up to the given no. of days, simulating one day at a time.
+fn print_histograms<R: Ring>(
+    ring: &R,
+    species: &Species,
+    fish_counts: &HashMap<Fish, usize>,
+    n_days: u64,
+) where
+    R::Elem: fmt::Display,
+{
+    let transition = transition_matrix(ring, species);
+    let mut counts = count_vector(ring, species, fish_counts);
+    for day in 0..=n_days {
+        if day > 0 {
+            counts = transition.left_mul(ring, &counts);
+        }
+        let histogram = stage_histogram(ring, species, &counts);
+        let total = histogram
+            .iter()
+            .fold(ring.elem(0), |total, count| ring.add(&total, count));
+        let stage_counts: Vec<_> = histogram
+            .iter()
+            .enumerate()
+            .map(|(stage, count)| match stage {
+                0 => format!("R={}", count),
+                n_days => format!("{}={}", n_days, count),
+            })
+            .collect();
+        println!("day {}: total={} [{}]", day, total, stage_counts.join(" "));
+    }
+}
+
 fn main() {
-    // read fish states from stdin
+    // parse options from command line arguments:
+    // --days=N to simulate N days (default: 256)
+    // --modulus=M to compute the no. of lanternfish modulo M instead of exactly.
+    // --histogram to print the no. of lanternfish in each lifecycle stage every day.
+    // --reproduce-cycle, --newborn-delay, --mortality-age, --litter-size to configure
+    //   the lanternfish species, overriding any configuration given in the input.
+    let (mut n_days, mut modulus, mut show_histogram) = (256, None, false);
+    let mut species_args = Vec::new();
+    for arg in env::args().skip(1) {
+        let (flag, value) = arg.split_once('=').unwrap_or((&arg, ""));
+        match flag {
+            "--days" => n_days = value.parse().expect("Failed to parse days as unsigned int"),
+            "--modulus" => {
+                modulus = Some(
+                    value
+                        .parse()
+                        .expect("Failed to parse modulus as unsigned int"),
+                )
+            }
+            "--histogram" => show_histogram = true,
+            _ => species_args.push((flag.trim_start_matches("--").to_string(), value.to_string())),
+        }
+    }
+
+    // read species configuration and fish states from stdin
+    // species parameters may be given as key=value lines before the fish states.
     let mut input = String::new();
     stdin()
         .read_to_string(&mut input)
         .expect("Failed to read fish states form std in");
-    let fishes: Vec<_> = input
-        .trim_end()
-        .split(",")
-        .map(|s| s.parse::<u8>().expect("Failed to parse as unsigned int"))
-        .map(|n_days| Fish::Incubating(n_days))
+    let mut lines: Vec<_> = input.trim_end().lines().collect();
+    let fish_line = lines.pop().expect("Expected fish states in input");
+
+    let mut species = Species::default();
+    let species_params = lines
+        .into_iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let (key, value) = line
+                .split_once('=')
+                .expect("Expected species parameters in the format key=value");
+            (key.trim().to_string(), value.trim().to_string())
+        })
+        .chain(species_args);
+    for (key, value) in species_params {
+        species
+            .set(&key, &value)
+            .unwrap_or_else(|err| panic!("{}", err));
+    }
+    species.validate().unwrap_or_else(|err| panic!("{}", err));
+
+    let fishes: Vec<_> = fish_line
+        .split(',')
+        .map(|s| parse_fish(s, &species).unwrap_or_else(|err| panic!("{}", err)))
         .collect();
 
-    // simulate 256 days of lanternfish reproduction
-    let mut fish_counts = count_fishes(fishes.into_iter());
-    for _ in 1..=256 {
-        // compute counts grouped by kind of fish for current day
-        let mut new_counts = HashMap::new();
-        for (fish_kind, n_fishes) in fish_counts {
-            // since all n_fishes of the same fish kind act the same way
-            // we only need to to simulate each fish kind once and multiply the results
-            for new_fish_kind in fish_kind.simulate() {
-                // update count for new fish types in counts map
-                let count = new_counts.entry(new_fish_kind).or_default();
-                *count += n_fishes;
+    // simulate n_days of lanternfish reproduction
+    let fish_counts = count_fishes(fishes.into_iter());
+    match modulus {
+        Some(0) => panic!("Modulus must be positive"),
+        Some(modulus) => {
+            let ring = Modulo(modulus);
+            if show_histogram {
+                print_histograms(&ring, &species, &fish_counts, n_days);
             }
+            println!(
+                "no. of lanternfish (mod {}): {}",
+                modulus,
+                population(&ring, &species, &fish_counts, n_days)
+            )
+        }
+        None => {
+            if show_histogram {
+                print_histograms(&Exact, &species, &fish_counts, n_days);
+            }
+            println!(
+                "no. of lanternfish: {}",
+                population(&Exact, &species, &fish_counts, n_days)
+            )
         }
-        fish_counts = new_counts;
     }
-
-    // compute total number of fishes
-    println!(
-        "no. of lanternfish: {}",
-        fish_counts.values().map(|&v| v).sum::<usize>()
-    );
 }
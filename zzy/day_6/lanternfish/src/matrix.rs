@@ -0,0 +1,129 @@
+//!
+//! Anti Helmet
+//! Advent of Code
+//! Day 6: Lanternfish
+//! Matrix Arithmetic
+//!
+
+use crate::bignum::BigUint;
+
+/// Defines the arithmetic used to compute matrix entries.
+pub trait Ring {
+    type Elem: Clone;
+
+    /// Convert the given integer into an element of this ring.
+    fn elem(&self, value: u64) -> Self::Elem;
+    fn add(&self, a: &Self::Elem, b: &Self::Elem) -> Self::Elem;
+    fn mul(&self, a: &Self::Elem, b: &Self::Elem) -> Self::Elem;
+}
+
+/// Exact arithmetic on arbitrary precision integers.
+pub struct Exact;
+impl Ring for Exact {
+    type Elem = BigUint;
+
+    fn elem(&self, value: u64) -> BigUint {
+        BigUint::from_u64(value)
+    }
+    fn add(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        a.add(b)
+    }
+    fn mul(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        a.mul(b)
+    }
+}
+
+/// Modular arithmetic on integers modulo the wrapped modulus.
+pub struct Modulo(pub u64);
+impl Ring for Modulo {
+    type Elem = u64;
+
+    fn elem(&self, value: u64) -> u64 {
+        value % self.0
+    }
+    fn add(&self, a: &u64, b: &u64) -> u64 {
+        ((*a as u128 + *b as u128) % self.0 as u128) as u64
+    }
+    fn mul(&self, a: &u64, b: &u64) -> u64 {
+        ((*a as u128 * *b as u128) % self.0 as u128) as u64
+    }
+}
+
+/// Square matrix of ring elements stored in row major order.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Matrix<T> {
+    size: usize,
+    cells: Vec<T>,
+}
+impl<T: Clone> Matrix<T> {
+    /// Create a size x size matrix from the given integer rows.
+    pub fn from_rows<R: Ring<Elem = T>>(ring: &R, rows: &[Vec<u64>]) -> Self {
+        let size = rows.len();
+        assert!(rows.iter().all(|row| row.len() == size));
+        Self {
+            size,
+            cells: rows
+                .iter()
+                .flat_map(|row| row.iter().map(|&value| ring.elem(value)))
+                .collect(),
+        }
+    }
+
+    /// Create a size x size identity matrix.
+    pub fn identity<R: Ring<Elem = T>>(ring: &R, size: usize) -> Self {
+        Self {
+            size,
+            cells: (0..size * size)
+                .map(|i| ring.elem((i / size == i % size) as u64))
+                .collect(),
+        }
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> &T {
+        &self.cells[row * self.size + col]
+    }
+
+    /// Compute the matrix product of this and the other given matrix.
+    pub fn mul<R: Ring<Elem = T>>(&self, ring: &R, other: &Self) -> Self {
+        assert_eq!(self.size, other.size);
+        let size = self.size;
+        let cells = (0..size * size)
+            .map(|i| {
+                let (row, col) = (i / size, i % size);
+                (1..size).fold(ring.mul(self.get(row, 0), other.get(0, col)), |sum, k| {
+                    ring.add(&sum, &ring.mul(self.get(row, k), other.get(k, col)))
+                })
+            })
+            .collect();
+        Self { size, cells }
+    }
+
+    /// Raise this matrix to the given power by repeated squaring,
+    /// taking O(log exponent) matrix multiplications.
+    pub fn pow<R: Ring<Elem = T>>(&self, ring: &R, mut exponent: u64) -> Self {
+        let mut result = Self::identity(ring, self.size);
+        let mut square = self.clone();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result.mul(ring, &square);
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                square = square.mul(ring, &square);
+            }
+        }
+        result
+    }
+
+    /// Compute the product of the given row vector with this matrix.
+    pub fn left_mul<R: Ring<Elem = T>>(&self, ring: &R, vector: &[T]) -> Vec<T> {
+        assert_eq!(vector.len(), self.size);
+        (0..self.size)
+            .map(|col| {
+                (1..self.size).fold(ring.mul(&vector[0], self.get(0, col)), |sum, k| {
+                    ring.add(&sum, &ring.mul(&vector[k], self.get(k, col)))
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,113 @@
+//!
+//! Anti Helmet
+//! Advent of Code
+//! Day 6: Lanternfish
+//! Species Configuration
+//!
+
+/// Max no. of distinct fish states tracked for a species. The transition matrix is
+/// square in the no. of states & each matrix multiplication takes cubic time in it,
+/// so mortal species with a long lifecycle or mortality age would be infeasible to model.
+pub const MAX_FISH_STATES: usize = 256;
+
+/// Configures the lifecycle of a species of lanternfish.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Species {
+    /// No. of days between successive reproductions of an adult fish.
+    pub reproduce_cycle: u8,
+    /// No. of extra days a newborn fish incubates before its first reproduction cycle.
+    pub newborn_delay: u8,
+    /// Age in days at which a fish dies, or None if fish live forever.
+    pub mortality_age: Option<u32>,
+    /// No. of new fish spawned each time a fish reproduces.
+    pub litter_size: u32,
+}
+impl Default for Species {
+    /// Lanternfish species described by the puzzle: reproducing every 7 days with
+    /// newborns needing 2 more days for their first cycle.
+    fn default() -> Self {
+        Self {
+            reproduce_cycle: 7,
+            newborn_delay: 2,
+            mortality_age: None,
+            litter_size: 1,
+        }
+    }
+}
+impl Species {
+    /// Set the species parameter with the given key to the given value.
+    /// Keys are the field names of Species, with dashes accepted in place of underscores.
+    /// Returns an error message if the key does not name a species parameter or
+    /// the value is not a valid value of the parameter.
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        let err_msg = |_| {
+            format!(
+                "Failed to parse species parameter {} as unsigned int: {}",
+                key, value
+            )
+        };
+        match key.replace('-', "_").as_str() {
+            "reproduce_cycle" => match value.parse().map_err(err_msg)? {
+                0 => return Err("Reproduce cycle must be >= 1".to_string()),
+                cycle => self.reproduce_cycle = cycle,
+            },
+            "newborn_delay" => self.newborn_delay = value.parse().map_err(err_msg)?,
+            "mortality_age" => match value.parse().map_err(err_msg)? {
+                0 => return Err("Mortality age must be >= 1".to_string()),
+                age => self.mortality_age = Some(age),
+            },
+            "litter_size" => self.litter_size = value.parse().map_err(err_msg)?,
+            _ => return Err(format!("Unsupported species parameter: {}", key)),
+        }
+        Ok(())
+    }
+
+    /// Check that the species' lifecycle can be modelled, after setting its parameters.
+    /// Returns an error message if the newborn cycle overflows or the species requires
+    /// more than MAX_FISH_STATES fish states.
+    pub fn validate(&self) -> Result<(), String> {
+        if self
+            .reproduce_cycle
+            .checked_add(self.newborn_delay)
+            .is_none()
+        {
+            return Err(format!(
+                "Newborn cycle of reproduce cycle {} + newborn delay {} must be at most {}",
+                self.reproduce_cycle,
+                self.newborn_delay,
+                u8::MAX
+            ));
+        }
+        let n_states = self.n_stages().saturating_mul(self.n_ages());
+        if n_states > MAX_FISH_STATES {
+            return Err(format!(
+                "Species requires {} fish states ({} stages x {} ages), must be at most {}",
+                n_states,
+                self.n_stages(),
+                self.n_ages(),
+                MAX_FISH_STATES
+            ));
+        }
+        Ok(())
+    }
+
+    /// No. of days a newborn fish takes to reproduce for the first time.
+    /// Panics if the newborn cycle does not fit in u8: check with validate() first.
+    pub fn newborn_cycle(&self) -> u8 {
+        self.reproduce_cycle
+            .checked_add(self.newborn_delay)
+            .expect("Expected newborn cycle to fit in u8")
+    }
+
+    /// No. of distinct stages in the lifecycle of this species:
+    /// reproducing and incubating for 1 up to newborn_cycle - 1 days.
+    pub fn n_stages(&self) -> usize {
+        usize::max(self.newborn_cycle() as usize, 1)
+    }
+
+    /// No. of distinct ages tracked for this species.
+    /// Ages are only tracked for mortal species, so immortal fish all share age 0.
+    pub fn n_ages(&self) -> usize {
+        self.mortality_age.unwrap_or(1) as usize
+    }
+}
@@ -0,0 +1,214 @@
+//!
+//! Anti Helmet
+//! Advent of Code
+//! Day 6: Lanternfish
+//! Unit Tests
+//!
+
+use super::bignum::BigUint;
+use super::*;
+
+fn example_counts() -> HashMap<Fish, usize> {
+    count_fishes([3, 4, 3, 1, 2].iter().map(|&n_days| Fish {
+        stage: Stage::Incubating(n_days),
+        age: 0,
+    }))
+}
+
+/// Count the population by simulating each fish state one day at a time.
+fn simulate_population(species: &Species, fish_counts: &HashMap<Fish, usize>, n_days: u64) -> u64 {
+    let mut fish_counts: HashMap<Fish, u64> = fish_counts
+        .iter()
+        .map(|(&fish, &count)| (fish, count as u64))
+        .collect();
+    for _ in 0..n_days {
+        let mut new_counts = HashMap::new();
+        for (fish, n_fishes) in fish_counts {
+            for (new_fish, n_new_fishes) in fish.simulate(species) {
+                *new_counts.entry(new_fish).or_default() += n_fishes * n_new_fishes;
+            }
+        }
+        fish_counts = new_counts;
+    }
+    fish_counts.values().sum()
+}
+
+#[test]
+fn test_parse_fish() {
+    let species = Species::default();
+    assert_eq!(
+        parse_fish(" 8", &species),
+        Ok(Fish {
+            stage: Stage::Incubating(8),
+            age: 0,
+        })
+    );
+    assert_eq!(
+        parse_fish("0", &species),
+        Ok(Fish {
+            stage: Stage::Reproducing,
+            age: 0,
+        })
+    );
+    assert!(parse_fish("9", &species).unwrap_err().contains("at most 8"));
+
+    // fishes of a species reproducing every day can only be about to reproduce
+    let mut species = Species::default();
+    species.set("reproduce_cycle", "1").unwrap();
+    species.set("newborn_delay", "0").unwrap();
+    assert_eq!(
+        parse_fish("0", &species).map(|fish| fish.stage),
+        Ok(Stage::Reproducing)
+    );
+    assert!(parse_fish("1", &species).unwrap_err().contains("at most 0"));
+    assert!(parse_fish("x", &species).is_err());
+}
+
+#[test]
+fn test_population_exact() {
+    let fish_counts = example_counts();
+    for &(n_days, expected) in [(0, 5), (18, 26), (80, 5934), (256, 26984457539)].iter() {
+        assert_eq!(
+            population(&Exact, &Species::default(), &fish_counts, n_days),
+            BigUint::from_u64(expected)
+        );
+    }
+}
+
+#[test]
+fn test_population_modulo() {
+    let fish_counts = example_counts();
+    assert_eq!(
+        population(
+            &Modulo(1_000_000_007),
+            &Species::default(),
+            &fish_counts,
+            256
+        ),
+        26984457539 % 1_000_000_007
+    );
+    // exact and modular results should agree beyond the range of u64
+    let exact = population(&Exact, &Species::default(), &fish_counts, 1000).to_string();
+    let modulus = 1_000_000_007;
+    let exact_mod = exact
+        .bytes()
+        .fold(0, |rem, digit| (rem * 10 + (digit - b'0') as u64) % modulus);
+    assert_eq!(
+        population(&Modulo(modulus), &Species::default(), &fish_counts, 1000),
+        exact_mod
+    );
+}
+
+#[test]
+fn test_population_species() {
+    let fish_counts = example_counts();
+    let species_configs = [
+        [
+            ("reproduce_cycle", "5"),
+            ("newborn_delay", "3"),
+            ("litter_size", "2"),
+        ],
+        [
+            ("reproduce_cycle", "7"),
+            ("newborn_delay", "2"),
+            ("mortality_age", "12"),
+        ],
+        [
+            ("reproduce_cycle", "1"),
+            ("newborn_delay", "4"),
+            ("mortality_age", "3"),
+        ],
+    ];
+    for params in species_configs.iter() {
+        let mut species = Species::default();
+        for (key, value) in params.iter() {
+            assert_eq!(species.set(key, value), Ok(()));
+        }
+        for &n_days in [0, 1, 9, 40].iter() {
+            assert_eq!(
+                population(&Modulo(u64::MAX), &species, &fish_counts, n_days),
+                simulate_population(&species, &fish_counts, n_days)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_simulate_litter() {
+    let mut species = Species::default();
+    species.set("litter_size", "4000000000").unwrap();
+    let fish = Fish {
+        stage: Stage::Reproducing,
+        age: 0,
+    };
+    // a litter is a single newborn standing for every fish in the litter
+    assert_eq!(
+        fish.simulate(&species),
+        vec![
+            (Fish::from_index(&species, 6), 1),
+            (Fish::from_index(&species, 8), 4_000_000_000),
+        ]
+    );
+}
+
+#[test]
+fn test_species_set() {
+    let mut species = Species::default();
+    assert_eq!(species.set("newborn-delay", "3"), Ok(()));
+    assert_eq!(species.newborn_delay, 3);
+    assert!(species.set("reproduce_cycle", "0").is_err());
+    assert!(species.set("mortality_age", "0").is_err());
+    assert!(species.set("litter_size", "-1").is_err());
+    assert!(species.set("lifespan", "3").is_err());
+    // failed parameters leave the species unchanged
+    assert_eq!(
+        species,
+        Species {
+            newborn_delay: 3,
+            ..Species::default()
+        }
+    );
+}
+
+#[test]
+fn test_species_validate() {
+    let mut species = Species::default();
+    assert_eq!(species.validate(), Ok(()));
+    species.set("reproduce_cycle", "250").unwrap();
+    species.set("newborn_delay", "6").unwrap();
+    assert!(species.validate().unwrap_err().contains("Newborn cycle"));
+
+    // fish states grow with the mortality age of mortal species
+    let mut species = Species::default();
+    species.set("mortality_age", "28").unwrap();
+    assert_eq!(species.validate(), Ok(()));
+    species.set("mortality_age", "29").unwrap();
+    assert!(species.validate().unwrap_err().contains("261 fish states"));
+}
+
+#[test]
+fn test_stage_histogram() {
+    let species = Species::default();
+    let ring = Modulo(u64::MAX);
+    let counts = transition_matrix(&ring, &species)
+        .pow(&ring, 18)
+        .left_mul(&ring, &count_vector(&ring, &species, &example_counts()));
+    // after 18 days: 6,0,6,4,5,6,0,1,1,2,6,0,1,1,1,2,2,3,3,4,6,7,8,8,8,8
+    assert_eq!(
+        stage_histogram(&ring, &species, &counts),
+        vec![3, 5, 3, 2, 2, 1, 5, 1, 4]
+    );
+}
+
+#[test]
+fn test_biguint_display() {
+    let big = BigUint::from_u64(u64::MAX).mul(&BigUint::from_u64(u64::MAX));
+    assert_eq!(big.to_string(), "340282366920938463426481119284349108225");
+    assert_eq!(BigUint::zero().to_string(), "0");
+    assert_eq!(
+        BigUint::from_u64(999_999_999)
+            .add(&BigUint::from_u64(1))
+            .to_string(),
+        "1000000000"
+    );
+}
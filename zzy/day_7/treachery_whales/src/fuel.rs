@@ -0,0 +1,159 @@
+//!
+//! Anti Helmet
+//! Advent of Code
+//! Day 7: The Treachery of Whales
+//! Fuel Costs
+//!
+
+#[cfg(test)]
+mod tests;
+
+/// Defines the fuel consumed by a crab submarine to move a given no. of steps.
+pub trait FuelCost {
+    /// Compute the fuel consumed by moving the given non-negative no. of steps.
+    fn cost(&self, steps: i64) -> i64;
+
+    /// Whether the cost is convex and non-decreasing in the no. of steps.
+    /// Such costs make the total fuel convex in the target position,
+    /// which allows the optimal position to be found by search instead of a full scan.
+    fn is_convex(&self) -> bool {
+        false
+    }
+
+    /// Candidate target positions known to contain the optimal position
+    /// for the given sorted crab positions, if the cost admits a closed form bound.
+    fn candidates(&self, _positions: &[i64]) -> Option<Vec<i64>> {
+        None
+    }
+}
+
+/// Each step costs 1 fuel.
+pub struct Linear;
+impl FuelCost for Linear {
+    fn cost(&self, steps: i64) -> i64 {
+        steps
+    }
+    fn is_convex(&self) -> bool {
+        true
+    }
+    /// Total fuel is minimised at the median position.
+    fn candidates(&self, positions: &[i64]) -> Option<Vec<i64>> {
+        Some(vec![positions[(positions.len() - 1) / 2]])
+    }
+}
+
+/// Each step costs 1 more fuel than the last: n steps cost 1 + 2 + ... + n.
+pub struct Triangular;
+impl FuelCost for Triangular {
+    fn cost(&self, steps: i64) -> i64 {
+        // use the summation formula to compute fuel cost: (n*(n+1)) / 2
+        steps * (steps + 1) / 2
+    }
+    fn is_convex(&self) -> bool {
+        true
+    }
+    /// Total fuel is minimised within half a step of the mean position.
+    fn candidates(&self, positions: &[i64]) -> Option<Vec<i64>> {
+        let (lower, upper) = mean_bounds(positions);
+        Some((lower - 1..=upper + 1).collect())
+    }
+}
+
+/// n steps cost n^2 fuel.
+pub struct Quadratic;
+impl FuelCost for Quadratic {
+    fn cost(&self, steps: i64) -> i64 {
+        steps * steps
+    }
+    fn is_convex(&self) -> bool {
+        true
+    }
+    /// Total fuel is minimised at the mean position.
+    fn candidates(&self, positions: &[i64]) -> Option<Vec<i64>> {
+        let (lower, upper) = mean_bounds(positions);
+        Some((lower..=upper).collect())
+    }
+}
+
+/// n steps cost c0 + c1*n + c2*n^2 + ... fuel for the wrapped coefficients c0, c1, c2...
+pub struct Polynomial(pub Vec<i64>);
+impl FuelCost for Polynomial {
+    fn cost(&self, steps: i64) -> i64 {
+        // evaluate with horner's method
+        self.0
+            .iter()
+            .rev()
+            .fold(0, |total, coefficient| total * steps + coefficient)
+    }
+    /// Polynomials with non-negative coefficients are convex and non-decreasing for n >= 0.
+    fn is_convex(&self) -> bool {
+        self.0.iter().all(|&coefficient| coefficient >= 0)
+    }
+}
+
+/// Compute the floor and ceiling of the mean of the given positions.
+fn mean_bounds(positions: &[i64]) -> (i64, i64) {
+    let (sum, n) = (positions.iter().sum::<i64>(), positions.len() as i64);
+    (sum.div_euclid(n), (sum + n - 1).div_euclid(n))
+}
+
+/// Defines the target position that crabs align to and the fuel required to do so.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Alignment {
+    pub position: i64,
+    pub fuel: i64,
+}
+
+/// Compute the fuel required to align crabs at the given positions to the target position.
+pub fn total_fuel<C: FuelCost + ?Sized>(cost: &C, positions: &[i64], target: i64) -> i64 {
+    positions
+        .iter()
+        .map(|pos| cost.cost(i64::abs(target - pos)))
+        .sum()
+}
+
+/// Find the target position that requires the least fuel to align crabs at the given
+/// positions, picking the leftmost position on ties. Tries, in order:
+/// - evaluating the cost's closed form candidate positions: O(n log n).
+/// - binary searching the slope of total fuel for convex costs: O(n log range).
+/// - evaluating every position between the leftmost and rightmost crab: O(n range).
+pub fn align<C: FuelCost + ?Sized>(cost: &C, positions: &[i64]) -> Alignment {
+    assert!(!positions.is_empty(), "Expected at least one crab position");
+    let mut positions = positions.to_vec();
+    positions.sort_unstable();
+    let (min_pos, max_pos) = (positions[0], positions[positions.len() - 1]);
+
+    let evaluate = |target| Alignment {
+        position: target,
+        fuel: total_fuel(cost, &positions, target),
+    };
+    let best = |targets: &mut dyn Iterator<Item = i64>| {
+        targets
+            .map(evaluate)
+            .min_by_key(|alignment| (alignment.fuel, alignment.position))
+            .unwrap()
+    };
+
+    if let Some(candidates) = cost.candidates(&positions) {
+        // clamp candidates to the crab positions as the optimum never lies outside them
+        best(
+            &mut candidates
+                .into_iter()
+                .map(|pos| pos.clamp(min_pos, max_pos)),
+        )
+    } else if cost.is_convex() {
+        // find the leftmost position where total fuel stops decreasing
+        let (mut lower, mut upper) = (min_pos, max_pos);
+        while lower < upper {
+            let mid = lower + (upper - lower) / 2;
+            if total_fuel(cost, &positions, mid + 1) >= total_fuel(cost, &positions, mid) {
+                upper = mid;
+            } else {
+                lower = mid + 1;
+            }
+        }
+        evaluate(lower)
+    } else {
+        best(&mut (min_pos..=max_pos))
+    }
+}
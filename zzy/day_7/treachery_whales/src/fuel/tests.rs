@@ -0,0 +1,90 @@
+//!
+//! Anti Helmet
+//! Advent of Code
+//! Day 7: The Treachery of Whales
+//! Unit Tests
+//!
+
+use super::*;
+
+const EXAMPLE: [i64; 10] = [16, 1, 2, 0, 4, 2, 7, 1, 2, 14];
+
+/// Find the optimal alignment by evaluating every position between the crabs.
+fn scan<C: FuelCost + ?Sized>(cost: &C, positions: &[i64]) -> Alignment {
+    let (&min_pos, &max_pos) = (
+        positions.iter().min().unwrap(),
+        positions.iter().max().unwrap(),
+    );
+    (min_pos..=max_pos)
+        .map(|position| Alignment {
+            position,
+            fuel: total_fuel(cost, positions, position),
+        })
+        .min_by_key(|alignment| (alignment.fuel, alignment.position))
+        .unwrap()
+}
+
+#[test]
+fn test_align_example() {
+    assert_eq!(
+        align(&Linear, &EXAMPLE),
+        Alignment {
+            position: 2,
+            fuel: 37
+        }
+    );
+    assert_eq!(
+        align(&Triangular, &EXAMPLE),
+        Alignment {
+            position: 5,
+            fuel: 168
+        }
+    );
+}
+
+#[test]
+fn test_candidates() {
+    let mut positions = EXAMPLE.to_vec();
+    positions.sort_unstable();
+    // median of the example positions
+    assert_eq!(Linear.candidates(&positions), Some(vec![2]));
+    // mean of the example positions is 4.9
+    assert_eq!(mean_bounds(&positions), (4, 5));
+    assert_eq!(Quadratic.candidates(&positions), Some(vec![4, 5]));
+    assert_eq!(Triangular.candidates(&positions), Some(vec![3, 4, 5, 6]));
+    assert_eq!(mean_bounds(&[-3, -2]), (-3, -2));
+    for cost in [&Linear as &dyn FuelCost, &Triangular, &Quadratic].iter() {
+        assert_eq!(align(*cost, &EXAMPLE), scan(*cost, &EXAMPLE));
+    }
+}
+
+#[test]
+fn test_polynomial() {
+    let cost = Polynomial(vec![1, 2, 3]);
+    assert_eq!(cost.cost(0), 1);
+    assert_eq!(cost.cost(2), 1 + 2 * 2 + 3 * 4);
+    assert!(cost.is_convex());
+    assert!(cost.candidates(&EXAMPLE).is_none());
+    assert!(!Polynomial(vec![0, 3, -1]).is_convex());
+}
+
+#[test]
+fn test_align_convex_search() {
+    // polynomial costs without closed form candidates are found by binary search
+    let positions = [3, -8, 40, 41, 12, 0, 7, 7, 95];
+    for coefficients in [vec![0, 1], vec![5, 0, 1], vec![0, 1, 2, 1]].iter() {
+        let cost = Polynomial(coefficients.clone());
+        assert_eq!(align(&cost, &positions), scan(&cost, &positions));
+    }
+    // ties are broken by picking the leftmost position
+    assert_eq!(
+        align(&Polynomial(vec![0, 1]), &[0, 4]),
+        Alignment {
+            position: 0,
+            fuel: 4
+        }
+    );
+    // non convex costs fall back to scanning every position
+    let cost = Polynomial(vec![0, 10, -1]);
+    assert_eq!(align(&cost, &positions), scan(&cost, &positions));
+}
@@ -1,38 +1,48 @@
-///!
-///! Anti-Helmet
-///! Advent of Code
-///! Day 7: The Treachery of Whales
-///!
+//!
+//! Anti-Helmet
+//! Advent of Code
+//! Day 7: The Treachery of Whales
+//!
 
+use std::env;
 use std::io::{stdin, Read};
 
+mod fuel;
+use fuel::*;
+
 fn main() {
+  // parse the fuel cost function from command line arguments:
+  // --cost=linear|triangular|quadratic|poly:C0,C1,C2,... (default: triangular)
+  // where poly defines a cost of C0 + C1*n + C2*n^2 + ... for n steps.
+  let mut cost: Box<dyn FuelCost> = Box::new(Triangular);
+  for arg in env::args().skip(1) {
+    let (flag, value) = arg.split_once('=').expect("Expected options in the format --flag=value");
+    match (flag, value) {
+      ("--cost", "linear") => cost = Box::new(Linear),
+      ("--cost", "triangular") => cost = Box::new(Triangular),
+      ("--cost", "quadratic") => cost = Box::new(Quadratic),
+      ("--cost", value) if value.starts_with("poly:") => {
+        cost = Box::new(Polynomial(
+          value["poly:".len()..]
+            .split(',')
+            .map(|s| s.parse().expect("Failed to parse coefficient as integer"))
+            .collect(),
+        ))
+      }
+      _ => panic!("Unsupported option: {}", arg),
+    }
+  }
+
   // read crab submarine positions from stdin
   let mut input = String::new();
   stdin().read_to_string(&mut input).expect("Failed to read crab submarine positions.");
   let positions: Vec<_> = input.trim_end()
     .split(",")
-    .map(|s| s.parse::<i32>().expect("Failed to parse crab submarine position as u32."))
+    .map(|s| s.parse::<i64>().expect("Failed to parse crab submarine position as integer."))
     .collect();
 
-  // find position search bonds by finding min/max crab positions
-  let &min_pos = positions.iter().min().unwrap();
-  let &max_pos = positions.iter().max().unwrap();
-
-  // brute force and evalulate fuel consumption for each possible target position
-  let min_fuel = (min_pos..=max_pos).map(|target_pos| {
-    // compute fuel required to move the target position
-    positions.iter()
-      .map(|pos| {
-        let steps = i32::abs(target_pos - pos);
-        // taking n steps to position n costs fuel: 1 + 2 + ... + n
-        // use the summation formula to compute fuel cost: (n*(n+1)) / 2
-        steps * (steps + 1) / 2
-      })
-      .sum::<i32>()
-  })
-  .min()
-  .unwrap();
+  // find the optimal target position with the least fuel consumption
+  let alignment = align(cost.as_ref(), &positions);
 
-  println!("Min fuel required: {}", min_fuel);
+  println!("Optimal position: {}, min fuel required: {}", alignment.position, alignment.fuel);
 }
\ No newline at end of file
@@ -0,0 +1,186 @@
+//!
+//! Anti-Helmet
+//! Advent of Code
+//! Day 8: Seven Segment Search
+//! Segment Displays
+//!
+
+/// Set of segments (or wires) encoded as a bitmask, where bit i is set if the
+/// segment named by the i-th lowercase letter is lit.
+pub type Pattern = u32;
+
+/// Parse the given string of lowercase segment names as a pattern.
+/// Returns None if the string contains a character that is not a lowercase letter.
+pub fn parse_pattern(pattern_str: &str) -> Option<Pattern> {
+    pattern_str.chars().try_fold(0, |pattern, c| {
+        if c.is_ascii_lowercase() {
+            Some(pattern | 1 << (c as u8 - b'a'))
+        } else {
+            None
+        }
+    })
+}
+
+/// Format the given pattern as a string of segment names in alphabetical order.
+pub fn format_pattern(pattern: Pattern) -> String {
+    (0..32)
+        .filter(|i| pattern & 1 << i != 0)
+        .map(|i| (b'a' + i as u8) as char)
+        .collect()
+}
+
+/// Segments lit to show each symbol on a fourteen segment display.
+const FOURTEEN_SEGMENT_FONT: &[(char, &str)] = &[
+    ('0', "abcdefjn"),
+    ('1', "bcj"),
+    ('2', "abdegk"),
+    ('3', "abcdk"),
+    ('4', "bcfgk"),
+    ('5', "adfgm"),
+    ('6', "acdefgk"),
+    ('7', "abc"),
+    ('8', "abcdefgk"),
+    ('9', "abcdfgk"),
+    ('A', "abcefgk"),
+    ('B', "abcdikl"),
+    ('C', "adef"),
+    ('D', "abcdil"),
+    ('E', "adefg"),
+    ('F', "aefg"),
+    ('G', "acdefk"),
+    ('H', "bcefgk"),
+    ('I', "adil"),
+    ('J', "bcde"),
+    ('K', "efgjm"),
+    ('L', "def"),
+    ('M', "bcefhj"),
+    ('N', "bcefhm"),
+    ('O', "abcdef"),
+    ('P', "abefgk"),
+    ('Q', "abcdefm"),
+    ('R', "abefgkm"),
+    ('S', "acdfgk"),
+    ('T', "ail"),
+    ('U', "bcdef"),
+    ('V', "efjn"),
+    ('W', "bcefmn"),
+    ('X', "hjmn"),
+    ('Y', "hjl"),
+    ('Z', "adjn"),
+];
+
+/// Defines a segment display by the segments lit to show each of its symbols.
+pub struct Display {
+    /// No. of segments in the display, named by the first n_segments lowercase letters.
+    pub n_segments: usize,
+    /// Symbols shown by the display with the segments lit to show them.
+    pub symbols: Vec<(char, Pattern)>,
+}
+impl Display {
+    /// Define a display from a table of symbols and the names of the segments lit to show them.
+    fn from_table<S: AsRef<str>>(n_segments: usize, table: &[(char, S)]) -> Self {
+        Self {
+            n_segments,
+            symbols: table
+                .iter()
+                .map(|(symbol, segments)| (*symbol, parse_pattern(segments.as_ref()).unwrap()))
+                .collect(),
+        }
+    }
+
+    /// Seven segment display showing the digits '0' to '9':
+    /// ```text
+    ///  aaaa
+    /// b    c
+    /// b    c
+    ///  dddd
+    /// e    f
+    /// e    f
+    ///  gggg
+    /// ```
+    pub fn seven_segment() -> Self {
+        Self::from_table(
+            7,
+            &[
+                ('0', "abcefg"),
+                ('1', "cf"),
+                ('2', "acdeg"),
+                ('3', "acdfg"),
+                ('4', "bcdf"),
+                ('5', "abdfg"),
+                ('6', "abdefg"),
+                ('7', "acf"),
+                ('8', "abcdefg"),
+                ('9', "abcdfg"),
+            ],
+        )
+    }
+
+    /// Fourteen segment display showing the digits '0' to '9' and letters 'A' to 'Z':
+    /// ```text
+    ///  aaaaaaa
+    /// fh  i  jb
+    /// f h i j b
+    ///  ggg kkk
+    /// e n l m c
+    /// en  l  mc
+    ///  ddddddd
+    /// ```
+    pub fn fourteen_segment() -> Self {
+        Self::from_table(14, FOURTEEN_SEGMENT_FONT)
+    }
+
+    /// Sixteen segment display showing the digits '0' to '9' and letters 'A' to 'Z'.
+    /// Extends the fourteen segment display by splitting its top and bottom segments
+    /// into left and right halves:
+    /// ```text
+    ///  aaa bbb
+    /// hk  l  mc
+    /// h k l m c
+    ///  iii jjj
+    /// g p o n d
+    /// gp  o  nd
+    ///  fff eee
+    /// ```
+    pub fn sixteen_segment() -> Self {
+        // sixteen segment names of each fourteen segment, in fourteen segment order.
+        const SPLIT_SEGMENTS: [&str; 14] = [
+            "ab", "c", "d", "ef", "g", "h", "i", "k", "l", "m", "j", "o", "n", "p",
+        ];
+        let table: Vec<_> = FOURTEEN_SEGMENT_FONT
+            .iter()
+            .map(|&(symbol, segments)| {
+                let split: String = segments
+                    .bytes()
+                    .map(|segment| SPLIT_SEGMENTS[(segment - b'a') as usize])
+                    .collect();
+                (symbol, split)
+            })
+            .collect();
+        Self::from_table(16, &table)
+    }
+
+    /// Retrieve the display with the given no. of segments, if it is defined.
+    pub fn with_segments(n_segments: usize) -> Option<Self> {
+        match n_segments {
+            7 => Some(Self::seven_segment()),
+            14 => Some(Self::fourteen_segment()),
+            16 => Some(Self::sixteen_segment()),
+            _ => None,
+        }
+    }
+
+    /// Retrieve a pattern with all segments of this display lit.
+    pub fn all_segments(&self) -> Pattern {
+        (1 << self.n_segments) - 1
+    }
+
+    /// Find the symbol shown when exactly the given segments are lit.
+    /// Returns None if the lit segments do not show any symbol.
+    pub fn decode(&self, segments: Pattern) -> Option<char> {
+        self.symbols
+            .iter()
+            .find(|(_, pattern)| *pattern == segments)
+            .map(|&(symbol, _)| symbol)
+    }
+}
@@ -1,126 +1,141 @@
-///!
-///! Anti-Helmet
-///! Advent of Code
-///! Day 8: Seven Segment Search
-///!
+//!
+//! Anti-Helmet
+//! Advent of Code
+//! Day 8: Seven Segment Search
+//!
+
+use std::env;
 use std::io::{stdin, Read};
-use std::iter::FromIterator;
-use std::collections::hash_map::RandomState;
-use std::collections::{HashMap, HashSet};
 
-type Pattern = HashSet<char, RandomState>;
+mod display;
+mod solver;
+use display::{format_pattern, parse_pattern, Display, Pattern};
+use solver::{Diagnosis, Solver, Wiring};
 
-/// Finds & Returns the segments in left that does not exists in the right segment
-fn diff_seg(left: &Pattern, right: &Pattern) -> HashSet<char> {
-    left.difference(right).map(|&c| c).collect()
+/// Parse the given whitespace separated scrambled patterns.
+/// Returns the first pattern that could not be parsed as an error.
+fn parse_patterns(patterns_str: &str) -> Result<Vec<Pattern>, String> {
+    patterns_str
+        .split_whitespace()
+        .map(|pattern_str| {
+            parse_pattern(pattern_str)
+                .ok_or_else(|| format!("unrecognised pattern {}", pattern_str))
+        })
+        .collect()
 }
 
-/// Checks whether the given signal pattern contains all segments in find_segs
-/// Returns true if all segments in find_segs are found in pattern, false otherwise.
-fn contains_segs(pattern: &Pattern, find_segs: &Pattern) -> bool {
-    diff_seg(find_segs, pattern).len() == 0
+/// Decode the given output patterns shown on the given display using the given wiring.
+fn decode_output(display: &Display, wiring: &Wiring, output_patterns: &[Pattern]) -> String {
+    output_patterns
+        .iter()
+        .map(|&pattern| display.decode(wiring.map(pattern)).unwrap_or('?'))
+        .collect()
 }
 
-/// Attempt to infer the digit represented by the given signal pattern.
-/// Uses a given infered map containing already infered digits to support inference.
-/// Returns the infered digit or none if there is insufficient context to infer the digit.
-fn infer(sig_pattern: &Pattern, infered: &HashMap<char, Pattern>) -> Option<char> {
-    match sig_pattern.len() {
-        // infer obvious digits identifiable by sig_pattern length
-        2 => Some('1'),
-        3 => Some('7'),
-        4 => Some('4'),
-        7 => Some('8'),
-        6 => {
-            // infer '6' / '9' / '4'  based on whether signal pattern contains
-            // specific segments
-            infered
-                .get(&'1')
-                .zip(infered.get(&'4'))
-                .map(|(one, four)| match sig_pattern {
-                    pat if contains_segs(pat, four) => '9',
-                    pat if contains_segs(pat, one) => '0',
-                    _ => '6',
-                })
-        }
-        5 => {
-            // compute 'e' segment segment by diffing '8' with '9'
-            let e_seg = infered
-                .get(&'8')
-                .zip(infered.get(&'9'))
-                .map(|(eight, nine)| diff_seg(eight, nine));
-
-            e_seg.zip(infered.get(&'1')).map(|(e_seg, one)| {
-                // infer '2' / '3' / '5' based on whether signal patterns
-                // contain specific segments
-                match sig_pattern {
-                    pat if contains_segs(pat, one) => '3',
-                    pat if contains_segs(pat, &e_seg) => '2',
-                    _ => '5',
-                }
-            })
+fn main() {
+    // parse the display the signal patterns are shown on from command line arguments:
+    // --display=7|14|16 to select the seven, fourteen or sixteen segment display (default: 7)
+    // --max-wirings=N to list at most N wirings for ambiguous entries (default: 10)
+    let mut display = Display::seven_segment();
+    let mut max_wirings = 10;
+    for arg in env::args().skip(1) {
+        match arg.split_once('=') {
+            Some(("--max-wirings", value)) => {
+                max_wirings = value
+                    .parse()
+                    .expect("Failed to parse max wirings as unsigned int")
+            }
+            Some(("--display", n_segments)) => {
+                display = n_segments
+                    .parse()
+                    .ok()
+                    .and_then(Display::with_segments)
+                    .unwrap_or_else(|| panic!("Unsupported display: {}", n_segments))
+            }
+            _ => panic!("Unsupported option: {}", arg),
         }
-        _ => panic!("Got an sig pattern with an unexpected no. of segments"),
     }
-}
 
-fn main() {
     // read signal pattern & output digits from stdin
     let mut input = String::new();
     stdin()
         .read_to_string(&mut input)
         .expect("Failed to read signal pattern & output digits from STDIN");
-    let input_cases = input.trim_end().split("\n").map(|line| {
-        line.split_at(
-            line.find("|")
-                .expect("Expected signal pattern & output digits to be delimited by '|'"),
-        )
-    });
 
-    // infer signal patterns and decode the output number in each test case
-    let mut output_sum: u32 = 0;
-    for (sig_patterns, output_digits) in input_cases {
-        let sig_patterns = sig_patterns
-            .split_whitespace()
-            .map(|sig_pattern| HashSet::from_iter(sig_pattern.chars()));
+    // solve the wiring of each display and decode the output in each test case
+    let (mut n_solved, mut n_ambiguous, mut n_contradictory, mut n_malformed) = (0, 0, 0, 0);
+    let mut outputs = Vec::new();
+    for (i_entry, line) in input.trim_end().split('\n').enumerate() {
+        let entry_no = i_entry + 1;
+        let patterns = line
+            .split_once('|')
+            .ok_or_else(|| "expected signal patterns & output to be delimited by '|'".to_string())
+            .and_then(|(sig_patterns, output_patterns)| {
+                Ok((
+                    parse_patterns(sig_patterns)?,
+                    parse_patterns(output_patterns)?,
+                ))
+            });
+        let (sig_patterns, output_patterns) = match patterns {
+            Ok(patterns) => patterns,
+            Err(err) => {
+                println!("entry {}: malformed: {}", entry_no, err);
+                n_malformed += 1;
+                continue;
+            }
+        };
 
-        // infer digit represented by the signal pattern over 3 passes into inference map
-        let mut infered: HashMap<char, Pattern> = HashMap::new();
-        for _pass in 1..=3 {
-            infered = sig_patterns
-                .clone()
-                .flat_map(|pattern| {
-                    let digit = infer(&pattern, &infered);
-                    digit.zip(Some(pattern))
-                })
-                .collect();
-        }
-        // reverse infered map to form mapping from signal pattern to digit
-        let pattern_digit_map: Vec<_> = infered
-            .into_iter()
-            .map(|(digit, pattern)| (pattern, digit))
+        // output patterns constrain the wiring as well as the signal patterns
+        let all_patterns: Vec<_> = sig_patterns
+            .iter()
+            .chain(&output_patterns)
+            .copied()
             .collect();
-
-        // use pattern digit map to parse output number
-        let output_number: u32 = output_digits
-            .split_whitespace()
-            // skip token as it will be the delimiter '|'
-            .skip(1)
-            .map(|digit_pattern| HashSet::from_iter(digit_pattern.chars()))
-            .map(|digit_pattern| {
-                // find digit that matches digit pattern
-                pattern_digit_map
-                    .iter()
-                    .filter(|(pattern, _)| digit_pattern == *pattern)
-                    .map(|(_, digit)| digit)
-                    .next()
-                    .expect("Encounted unknown signal pattern when parsing output digits")
-            })
-            .collect::<String>()
-            .parse()
-            .expect("Failed to parse output number as integer");
-        output_sum += output_number;
+        match Solver::new(&display, &all_patterns).diagnose(max_wirings) {
+            Diagnosis::Solved(wiring) => {
+                let output = decode_output(&display, &wiring, &output_patterns);
+                println!("entry {}: solved: {} => {}", entry_no, wiring, output);
+                n_solved += 1;
+                outputs.push(output);
+            }
+            Diagnosis::Ambiguous { wirings, truncated } => {
+                println!(
+                    "entry {}: ambiguous: {}{} consistent wirings",
+                    entry_no,
+                    if truncated { "more than " } else { "" },
+                    wirings.len()
+                );
+                for wiring in wirings {
+                    let output = decode_output(&display, &wiring, &output_patterns);
+                    println!("    {} => {}", wiring, output);
+                }
+                n_ambiguous += 1;
+            }
+            Diagnosis::Contradictory(conflicts) => {
+                let conflicts: Vec<_> = conflicts.into_iter().map(format_pattern).collect();
+                println!(
+                    "entry {}: contradictory: conflicting patterns {}",
+                    entry_no,
+                    conflicts.join(" ")
+                );
+                n_contradictory += 1;
+            }
+        }
     }
 
-    println!("Sum of all output values: {}", output_sum);
+    println!(
+        "Summary: {} solved, {} ambiguous, {} contradictory, {} malformed",
+        n_solved, n_ambiguous, n_contradictory, n_malformed
+    );
+    // sum outputs of solved entries if there are any and they are all numbers
+    let output_numbers: Result<Vec<_>, _> = outputs.iter().map(|s| s.parse::<u32>()).collect();
+    match output_numbers {
+        Ok(output_numbers) if n_solved > 0 => {
+            println!(
+                "Sum of solved output values: {}",
+                output_numbers.iter().sum::<u32>()
+            );
+        }
+        _ => (),
+    }
 }
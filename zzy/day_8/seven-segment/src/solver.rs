@@ -0,0 +1,293 @@
+//!
+//! Anti-Helmet
+//! Advent of Code
+//! Day 8: Seven Segment Search
+//! Wiring Solver
+//!
+
+use crate::display::{Display, Pattern};
+use std::fmt;
+
+#[cfg(test)]
+mod tests;
+
+/// Defines how the wires of a scrambled display connect to its segments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Wiring {
+    /// Index of the segment connected to each wire, indexed by wire.
+    segments: Vec<u8>,
+}
+impl Wiring {
+    /// Map the given pattern of lit wires to the pattern of segments they light.
+    pub fn map(&self, wires: Pattern) -> Pattern {
+        self.segments
+            .iter()
+            .enumerate()
+            .filter(|(wire, _)| wires & 1 << wire != 0)
+            .fold(0, |segments, (_, segment)| segments | 1 << segment)
+    }
+}
+impl fmt::Display for Wiring {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        let wire_segments: Vec<_> = self
+            .segments
+            .iter()
+            .enumerate()
+            .map(|(wire, &segment)| {
+                format!(
+                    "{}->{}",
+                    (b'a' + wire as u8) as char,
+                    (b'a' + segment) as char
+                )
+            })
+            .collect();
+        write!(out, "{}", wire_segments.join(" "))
+    }
+}
+
+/// Classifies the wirings consistent with the patterns of a scrambled display.
+/// Wirings that only differ by swapping interchangeable segments are counted once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Diagnosis {
+    /// Exactly one wiring is consistent with the patterns.
+    Solved(Wiring),
+    /// Multiple wirings are consistent with the patterns.
+    /// Truncated is set if there are more consistent wirings than those listed.
+    Ambiguous {
+        wirings: Vec<Wiring>,
+        truncated: bool,
+    },
+    /// No wiring is consistent with the patterns. Lists a minimal set of
+    /// patterns that cannot all be shown on the display at once.
+    Contradictory(Vec<Pattern>),
+}
+
+/// Finds wirings that map scrambled patterns onto the symbols of a display by
+/// constraint propagation and backtracking.
+///
+/// Each wire tracks a domain of segments it may still be connected to. Propagation
+/// narrows the domains by requiring each pattern to light the segments of some symbol
+/// with the same no. of segments and that no two wires connect to the same segment.
+/// When propagation stalls, the solver branches on the wire with the smallest domain.
+///
+/// Segments lit by exactly the same symbols are interchangeable: swapping the wires
+/// connected to them decodes every pattern to the same symbol. Of the wirings that
+/// only differ by such swaps, only the one connecting the lower wire to the lower
+/// segment of each interchangeable pair is reported.
+pub struct Solver<'a> {
+    display: &'a Display,
+    patterns: Vec<Pattern>,
+    /// Pairs of interchangeable segments, lower segment first.
+    interchangeable: Vec<(usize, usize)>,
+}
+impl<'a> Solver<'a> {
+    /// Create a solver for the given scrambled patterns shown on the given display.
+    /// Duplicate patterns are ignored.
+    pub fn new(display: &'a Display, patterns: &[Pattern]) -> Self {
+        let mut patterns = patterns.to_vec();
+        patterns.sort_unstable();
+        patterns.dedup();
+        let lit_by = |segment: usize| -> Vec<bool> {
+            display
+                .symbols
+                .iter()
+                .map(|(_, symbol)| symbol & 1 << segment != 0)
+                .collect()
+        };
+        let mut interchangeable = Vec::new();
+        for segment in 0..display.n_segments {
+            for other in segment + 1..display.n_segments {
+                if lit_by(segment) == lit_by(other) {
+                    interchangeable.push((segment, other));
+                }
+            }
+        }
+        Self {
+            display,
+            patterns,
+            interchangeable,
+        }
+    }
+
+    /// Find the first wiring consistent with the scrambled patterns.
+    /// Returns None if no consistent wiring exists.
+    pub fn solve(&self) -> Option<Wiring> {
+        self.solutions(1).pop()
+    }
+
+    /// Classify the wirings consistent with the scrambled patterns, listing up to
+    /// limit wirings if the patterns are ambiguous. At least 2 wirings are always
+    /// searched for, so that ambiguous patterns are still told apart with a limit of 0.
+    pub fn diagnose(&self, limit: usize) -> Diagnosis {
+        // patterns lighting wires the display does not have can never be shown
+        let unknown_wires: Vec<_> = self
+            .patterns
+            .iter()
+            .copied()
+            .filter(|pattern| pattern & !self.display.all_segments() != 0)
+            .collect();
+        if !unknown_wires.is_empty() {
+            return Diagnosis::Contradictory(unknown_wires);
+        }
+
+        let mut wirings = self.solutions(limit.max(1) + 1);
+        match wirings.len() {
+            0 => Diagnosis::Contradictory(self.conflicts()),
+            1 => Diagnosis::Solved(wirings.pop().unwrap()),
+            n_wirings => {
+                wirings.truncate(limit);
+                Diagnosis::Ambiguous {
+                    wirings,
+                    truncated: n_wirings > limit,
+                }
+            }
+        }
+    }
+
+    /// Find a minimal set of conflicting patterns among the patterns, assuming that
+    /// no wiring is consistent with all of them. Drops each pattern in turn, keeping
+    /// it out if the remaining patterns still conflict.
+    fn conflicts(&self) -> Vec<Pattern> {
+        let mut conflicts = self.patterns.clone();
+        let mut i = 0;
+        while i < conflicts.len() {
+            let mut remaining = conflicts.clone();
+            remaining.remove(i);
+            let solver = Solver {
+                display: self.display,
+                patterns: remaining,
+                interchangeable: self.interchangeable.clone(),
+            };
+            if solver.solve().is_none() {
+                conflicts = solver.patterns;
+            } else {
+                i += 1;
+            }
+        }
+        conflicts
+    }
+
+    /// Find up to limit wirings consistent with the scrambled patterns.
+    pub fn solutions(&self, limit: usize) -> Vec<Wiring> {
+        let mut solutions = Vec::new();
+        let domains = vec![self.display.all_segments(); self.display.n_segments];
+        self.search(domains, limit, &mut solutions);
+        solutions
+    }
+
+    /// Recursively search for wirings within the given wire domains, collecting
+    /// up to limit wirings in solutions.
+    fn search(&self, mut domains: Vec<Pattern>, limit: usize, solutions: &mut Vec<Wiring>) {
+        if solutions.len() >= limit || !self.propagate(&mut domains) {
+            return;
+        }
+        // branch on the undecided wire with the fewest candidate segments
+        let branch_wire = (0..domains.len())
+            .filter(|&wire| domains[wire].count_ones() > 1)
+            .min_by_key(|&wire| domains[wire].count_ones());
+        match branch_wire {
+            None => {
+                // wire connected to each segment: domains are distinct single segments
+                let mut wires = vec![0; domains.len()];
+                for (wire, domain) in domains.iter().enumerate() {
+                    wires[domain.trailing_zeros() as usize] = wire;
+                }
+                if self
+                    .interchangeable
+                    .iter()
+                    .all(|&(segment, other)| wires[segment] < wires[other])
+                {
+                    solutions.push(Wiring {
+                        segments: domains
+                            .iter()
+                            .map(|domain| domain.trailing_zeros() as u8)
+                            .collect(),
+                    });
+                }
+            }
+            Some(wire) => {
+                for segment in 0..self.display.n_segments {
+                    if domains[wire] & 1 << segment != 0 {
+                        let mut branch = domains.clone();
+                        branch[wire] = 1 << segment;
+                        self.search(branch, limit, solutions);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Narrow the given wire domains until no more segments can be ruled out.
+    /// Returns false if some wire has no segment left it could connect to.
+    fn propagate(&self, domains: &mut [Pattern]) -> bool {
+        loop {
+            let before = domains.to_vec();
+
+            // wires decided to connect to a segment rule it out for every other wire
+            for wire in 0..domains.len() {
+                if domains[wire].count_ones() == 1 {
+                    let segment = domains[wire];
+                    for (other, domain) in domains.iter_mut().enumerate() {
+                        if other != wire {
+                            *domain &= !segment;
+                        }
+                    }
+                }
+            }
+            if domains.contains(&0) {
+                return false;
+            }
+
+            for &pattern in self.patterns.iter() {
+                let candidates = self.candidates(pattern, domains);
+                if candidates.is_empty() {
+                    return false;
+                }
+                // lit wires must connect to a segment in some candidate symbol,
+                // unlit wires must avoid segments common to all candidate symbols
+                let union = candidates.iter().fold(0, |union, &symbol| union | symbol);
+                let intersect = candidates.iter().fold(!0, |inter, &symbol| inter & symbol);
+                for (wire, domain) in domains.iter_mut().enumerate() {
+                    if pattern & 1 << wire != 0 {
+                        *domain &= union;
+                    } else {
+                        *domain &= !intersect;
+                    }
+                }
+            }
+            if domains.contains(&0) {
+                return false;
+            }
+
+            if before == domains {
+                return true;
+            }
+        }
+    }
+
+    /// Find the symbol patterns the given scrambled pattern may map onto under
+    /// the given wire domains.
+    fn candidates(&self, pattern: Pattern, domains: &[Pattern]) -> Vec<Pattern> {
+        // segments lit / unlit by the decided wires in the scrambled pattern
+        let (mut lit, mut unlit) = (0, 0);
+        for (wire, &domain) in domains.iter().enumerate() {
+            if domain.count_ones() == 1 {
+                if pattern & 1 << wire != 0 {
+                    lit |= domain;
+                } else {
+                    unlit |= domain;
+                }
+            }
+        }
+        self.display
+            .symbols
+            .iter()
+            .map(|&(_, symbol)| symbol)
+            .filter(|symbol| {
+                symbol.count_ones() == pattern.count_ones()
+                    && symbol & lit == lit
+                    && symbol & unlit == 0
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,164 @@
+//!
+//! Anti-Helmet
+//! Advent of Code
+//! Day 8: Seven Segment Search
+//! Unit Tests
+//!
+
+use super::*;
+use crate::display::parse_pattern;
+
+fn patterns(patterns_str: &str) -> Vec<Pattern> {
+    patterns_str
+        .split_whitespace()
+        .map(|pattern_str| parse_pattern(pattern_str).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_solve_seven_segment() {
+    let display = Display::seven_segment();
+    let sig_patterns = patterns("acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab");
+    let wiring = Solver::new(&display, &sig_patterns).solve().unwrap();
+
+    assert_eq!(wiring.to_string(), "a->c b->f c->g d->a e->b f->d g->e");
+    let output: String = patterns("cdfeb fcadb cdfeb cdbaf")
+        .into_iter()
+        .map(|pattern| display.decode(wiring.map(pattern)).unwrap())
+        .collect();
+    assert_eq!(output, "5353");
+}
+
+#[test]
+fn test_solve_custom_table() {
+    // three segment display where symbols are only distinguishable by their segments
+    let display = Display {
+        n_segments: 3,
+        symbols: vec![
+            ('x', parse_pattern("a").unwrap()),
+            ('y', parse_pattern("ab").unwrap()),
+            ('z', parse_pattern("bc").unwrap()),
+        ],
+    };
+    let wiring = Solver::new(&display, &patterns("c ca ab")).solve().unwrap();
+    assert_eq!(wiring.to_string(), "a->b b->c c->a");
+
+    // a single symbol cannot determine the wiring
+    assert_eq!(Solver::new(&display, &patterns("b")).solutions(10).len(), 2);
+    // both two segment patterns share a wire with the single segment pattern
+    // but only one two segment symbol shares a segment with the single segment symbol
+    assert_eq!(Solver::new(&display, &patterns("ab bc b")).solve(), None);
+}
+
+/// Scramble the given symbols shown on the given display by connecting each
+/// wire to the segment with the index given in segments.
+fn scramble(display: &Display, segments: &[u8], symbols: &str) -> Vec<Pattern> {
+    symbols
+        .chars()
+        .map(|symbol| {
+            let (_, lit) = display.symbols.iter().find(|(s, _)| *s == symbol).unwrap();
+            (0..segments.len())
+                .filter(|&wire| lit & 1 << segments[wire] != 0)
+                .fold(0, |wires, wire| wires | 1 << wire)
+        })
+        .collect()
+}
+
+#[test]
+fn test_solve_alphanumeric() {
+    for (display, segments) in [
+        (
+            Display::fourteen_segment(),
+            &[3, 12, 0, 9, 6, 13, 1, 10, 4, 7, 2, 11, 5, 8][..],
+        ),
+        (
+            Display::sixteen_segment(),
+            &[15, 3, 12, 0, 9, 6, 13, 1, 14, 10, 4, 7, 2, 11, 5, 8][..],
+        ),
+    ]
+    .iter()
+    {
+        // each symbol should light a distinct set of segments
+        for (i, (_, pattern)) in display.symbols.iter().enumerate() {
+            assert!(display.symbols[i + 1..].iter().all(|(_, p)| p != pattern));
+        }
+
+        let sig_patterns = scramble(display, segments, "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ");
+        let wiring = Solver::new(display, &sig_patterns).solve().unwrap();
+        let output: String = scramble(display, segments, "HELLO2021")
+            .into_iter()
+            .map(|pattern| display.decode(wiring.map(pattern)).unwrap())
+            .collect();
+        assert_eq!(output, "HELLO2021");
+    }
+}
+
+#[test]
+fn test_diagnose() {
+    let display = Display::seven_segment();
+    let sig_patterns = patterns("acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab");
+    assert!(matches!(
+        Solver::new(&display, &sig_patterns).diagnose(10),
+        Diagnosis::Solved(_)
+    ));
+
+    // '1' and '7' alone leave the wires of the remaining 4 segments undetermined
+    match Solver::new(&display, &patterns("ab abd")).diagnose(100) {
+        Diagnosis::Ambiguous { wirings, truncated } => {
+            assert_eq!(wirings.len(), 2 * 4 * 3 * 2);
+            assert!(!truncated);
+        }
+        diagnosis => panic!("Expected ambiguous diagnosis, got {:?}", diagnosis),
+    }
+    match Solver::new(&display, &patterns("ab abd")).diagnose(5) {
+        Diagnosis::Ambiguous { wirings, truncated } => assert!(wirings.len() == 5 && truncated),
+        diagnosis => panic!("Expected ambiguous diagnosis, got {:?}", diagnosis),
+    }
+    // ambiguous patterns are still diagnosed as ambiguous without listing any wirings
+    match Solver::new(&display, &patterns("ab abd")).diagnose(0) {
+        Diagnosis::Ambiguous { wirings, truncated } => assert!(wirings.is_empty() && truncated),
+        diagnosis => panic!("Expected ambiguous diagnosis, got {:?}", diagnosis),
+    }
+
+    // two disjoint two segment patterns cannot both show '1'
+    assert_eq!(
+        Solver::new(&display, &patterns("abcdefg ab cd")).diagnose(10),
+        Diagnosis::Contradictory(patterns("ab cd"))
+    );
+    assert_eq!(
+        Solver::new(&display, &patterns("ab abcdefgh")).diagnose(10),
+        Diagnosis::Contradictory(patterns("abcdefgh"))
+    );
+}
+
+#[test]
+fn test_diagnose_alphanumeric() {
+    // split top & bottom segments of the sixteen segment display are always lit
+    // together, so the wires connected to either half cannot be told apart
+    let display = Display::sixteen_segment();
+    let segments = [15, 3, 12, 0, 9, 6, 13, 1, 14, 10, 4, 7, 2, 11, 5, 8];
+    let sig_patterns = scramble(&display, &segments, "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ");
+    let output_patterns = scramble(&display, &segments, "HELLO2021");
+    let all_patterns: Vec<_> = sig_patterns
+        .iter()
+        .chain(&output_patterns)
+        .copied()
+        .collect();
+    let wiring = match Solver::new(&display, &all_patterns).diagnose(10) {
+        Diagnosis::Solved(wiring) => wiring,
+        diagnosis => panic!("Expected solved diagnosis, got {:?}", diagnosis),
+    };
+    let output: String = output_patterns
+        .into_iter()
+        .map(|pattern| display.decode(wiring.map(pattern)).unwrap())
+        .collect();
+    assert_eq!(output, "HELLO2021");
+
+    let display = Display::fourteen_segment();
+    let segments = [3, 12, 0, 9, 6, 13, 1, 10, 4, 7, 2, 11, 5, 8];
+    let sig_patterns = scramble(&display, &segments, "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ");
+    assert!(matches!(
+        Solver::new(&display, &sig_patterns).diagnose(10),
+        Diagnosis::Solved(_)
+    ));
+}
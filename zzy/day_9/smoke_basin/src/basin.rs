@@ -0,0 +1,135 @@
+//!
+//! Anti-Helmet
+//! Advent of Code
+//! Day 9: Smoke Basin
+//! Basin Labelling
+//!
+
+use crate::Point;
+
+#[cfg(test)]
+mod tests;
+
+/// Height of the walls that separate basins.
+pub const WALL_HEIGHT: u8 = 9;
+
+/// Disjoint set forest used to merge connected points into basins.
+struct DisjointSet {
+    parents: Vec<usize>,
+    ranks: Vec<u8>,
+}
+impl DisjointSet {
+    fn new(size: usize) -> Self {
+        Self {
+            parents: (0..size).collect(),
+            ranks: vec![0; size],
+        }
+    }
+
+    /// Find the representative of the set containing the given element,
+    /// halving the path to the representative along the way.
+    fn find(&mut self, mut element: usize) -> usize {
+        while self.parents[element] != element {
+            self.parents[element] = self.parents[self.parents[element]];
+            element = self.parents[element];
+        }
+        element
+    }
+
+    /// Merge the sets containing the given elements by rank.
+    fn union(&mut self, a: usize, b: usize) {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return;
+        }
+        if self.ranks[a] < self.ranks[b] {
+            self.parents[a] = b;
+        } else {
+            self.parents[b] = a;
+            if self.ranks[a] == self.ranks[b] {
+                self.ranks[a] += 1;
+            }
+        }
+    }
+}
+
+/// Describes a basin of connected points bounded by walls.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Basin {
+    /// No. of points in the basin.
+    pub size: usize,
+    /// Lowest point in the basin, taking the first in row major order on ties.
+    pub low_pt: Point,
+    /// Top left corner of the basin's bounding box.
+    pub min_pt: Point,
+    /// Bottom right corner of the basin's bounding box.
+    pub max_pt: Point,
+}
+
+/// Labels each point in a height map with the basin it belongs to.
+pub struct BasinMap {
+    /// Basin label of each point indexed in y, x order, None for wall points.
+    pub labels: Vec<Vec<Option<usize>>>,
+    /// Basins indexed by label, labelled in row major order of their first point.
+    pub basins: Vec<Basin>,
+}
+impl BasinMap {
+    /// Label the basins in the given 2D heights by merging each non wall point
+    /// with its non wall neighbours to the right and below.
+    /// Runs iteratively in near linear time, so large maps cannot exhaust the stack.
+    pub fn new(heights: &[&[u8]]) -> Self {
+        let max_y = heights.len();
+        let max_x = heights.first().map_or(0, |row| row.len());
+        let index = |x: usize, y: usize| y * max_x + x;
+        let is_basin = |x: usize, y: usize| heights[y][x] < WALL_HEIGHT;
+
+        let mut sets = DisjointSet::new(max_x * max_y);
+        for y in 0..max_y {
+            for x in 0..max_x {
+                if !is_basin(x, y) {
+                    continue;
+                }
+                if x + 1 < max_x && is_basin(x + 1, y) {
+                    sets.union(index(x, y), index(x + 1, y));
+                }
+                if y + 1 < max_y && is_basin(x, y + 1) {
+                    sets.union(index(x, y), index(x, y + 1));
+                }
+            }
+        }
+
+        // assign labels to sets & collect basin statistics in row major order
+        let mut set_labels = vec![None; max_x * max_y];
+        let mut labels = vec![vec![None; max_x]; max_y];
+        let mut basins: Vec<Basin> = Vec::new();
+        for y in 0..max_y {
+            for x in 0..max_x {
+                if !is_basin(x, y) {
+                    continue;
+                }
+                let set = sets.find(index(x, y));
+                let label = *set_labels[set].get_or_insert_with(|| {
+                    basins.push(Basin {
+                        size: 0,
+                        low_pt: Point(x, y),
+                        min_pt: Point(x, y),
+                        max_pt: Point(x, y),
+                    });
+                    basins.len() - 1
+                });
+                labels[y][x] = Some(label);
+
+                let basin = &mut basins[label];
+                basin.size += 1;
+                let Point(low_x, low_y) = basin.low_pt;
+                if heights[y][x] < heights[low_y][low_x] {
+                    basin.low_pt = Point(x, y);
+                }
+                basin.min_pt = Point(usize::min(basin.min_pt.0, x), usize::min(basin.min_pt.1, y));
+                basin.max_pt = Point(usize::max(basin.max_pt.0, x), usize::max(basin.max_pt.1, y));
+            }
+        }
+
+        Self { labels, basins }
+    }
+}
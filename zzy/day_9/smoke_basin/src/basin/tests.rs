@@ -0,0 +1,68 @@
+//!
+//! Anti-Helmet
+//! Advent of Code
+//! Day 9: Smoke Basin
+//! Unit Tests
+//!
+
+use super::*;
+use crate::parse_heights;
+
+const EXAMPLE: &str = "2199943210
+3987894921
+9856789892
+8767896789
+9899965678";
+
+#[test]
+fn test_disjoint_set() {
+    let mut sets = DisjointSet::new(5);
+    sets.union(0, 1);
+    sets.union(3, 4);
+    sets.union(1, 4);
+    assert_eq!(sets.find(0), sets.find(3));
+    assert_eq!(sets.find(1), sets.find(4));
+    assert_ne!(sets.find(2), sets.find(0));
+    // merging already merged sets is a no-op
+    sets.union(4, 0);
+    assert_eq!(sets.find(0), sets.find(4));
+}
+
+#[test]
+fn test_basin_map_example() {
+    let heights = parse_heights(EXAMPLE);
+    let heights: Vec<_> = heights.iter().map(|row| row.as_slice()).collect();
+    let basin_map = BasinMap::new(&heights);
+
+    // basins are labelled in row major order of their first point
+    let sizes: Vec<_> = basin_map.basins.iter().map(|basin| basin.size).collect();
+    assert_eq!(sizes, vec![3, 9, 14, 9]);
+    let low_pts: Vec<_> = basin_map.basins.iter().map(|basin| basin.low_pt).collect();
+    assert_eq!(
+        low_pts,
+        vec![Point(1, 0), Point(9, 0), Point(2, 2), Point(6, 4)]
+    );
+    assert_eq!(basin_map.basins[0].min_pt, Point(0, 0));
+    assert_eq!(basin_map.basins[0].max_pt, Point(1, 1));
+    assert_eq!(
+        basin_map.labels[0][..5],
+        [Some(0), Some(0), None, None, None]
+    );
+
+    let mut sizes = sizes;
+    sizes.sort_unstable_by(|a, b| b.cmp(a));
+    assert_eq!(sizes.iter().take(3).product::<usize>(), 1134);
+}
+
+#[test]
+fn test_basin_map_walls() {
+    // basins touching only diagonally are separate, while walls belong to no basin
+    let heights = parse_heights("19\n91");
+    let heights: Vec<_> = heights.iter().map(|row| row.as_slice()).collect();
+    let basin_map = BasinMap::new(&heights);
+    assert_eq!(basin_map.basins.len(), 2);
+    assert_eq!(
+        basin_map.labels,
+        vec![vec![Some(0), None], vec![None, Some(1)]]
+    );
+}
@@ -0,0 +1,175 @@
+//!
+//! Anti-Helmet
+//! Advent of Code
+//! Day 9: Smoke Basin
+//! Drainage Analysis
+//!
+
+use crate::{surroundings, Point};
+use std::collections::{HashSet, VecDeque};
+
+#[cfg(test)]
+mod tests;
+
+/// Describes a sink that smoke settles in: a region of equal height points
+/// without any lower neighbouring points.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sink {
+    /// First point of the sink in row major order.
+    pub low_pt: Point,
+    pub height: u8,
+    /// No. of points that make up the sink.
+    pub size: usize,
+    /// No. of points that drain into the sink, including the sink's own points.
+    pub catchment: usize,
+}
+
+/// Models how smoke flows over a height map under steepest descent.
+///
+/// Smoke at a point flows to the neighbouring point with the greatest drop in height.
+/// When multiple neighbours tie for the greatest drop, smoke flows to the first of them
+/// in left, right, up, down order and the point is counted as tied. Smoke on a plateau
+/// of equal height points with no lower neighbour flows across the plateau towards its
+/// nearest point that does have a lower neighbour. Plateaus with no such point are sinks.
+pub struct Drainage {
+    /// Index of the sink each point drains to, indexed in y, x order.
+    pub sink_ids: Vec<Vec<usize>>,
+    /// Sinks indexed by sink id, in row major order of their first point.
+    pub sinks: Vec<Sink>,
+    /// No. of points where multiple neighbours tie for the steepest descent.
+    pub n_tied: usize,
+    /// No. of points that drain across a plateau before descending.
+    pub n_plateau: usize,
+}
+impl Drainage {
+    /// Analyse the drainage of smoke over the given 2D heights.
+    pub fn new(heights: &[&[u8]]) -> Self {
+        let max_y = heights.len();
+        let max_x = heights.first().map_or(0, |row| row.len());
+        let height = |Point(x, y): Point| heights[y][x];
+
+        // find where smoke flows from each point, None if the point has no lower neighbour
+        let mut downhill: Vec<Vec<Option<Point>>> = vec![vec![None; max_x]; max_y];
+        let mut n_tied = 0;
+        for y in 0..max_y {
+            for x in 0..max_x {
+                let lower: Vec<_> = surroundings(heights, Point(x, y))
+                    .into_iter()
+                    .filter(|&other| height(other) < heights[y][x])
+                    .collect();
+                let steepest = lower.iter().map(|&other| height(other)).min();
+                if let Some(steepest) = steepest {
+                    if lower
+                        .iter()
+                        .filter(|&&other| height(other) == steepest)
+                        .count()
+                        > 1
+                    {
+                        n_tied += 1;
+                    }
+                    downhill[y][x] = lower.into_iter().find(|&other| height(other) == steepest);
+                }
+            }
+        }
+
+        // route smoke across plateaus of equal height points without lower neighbours
+        let mut visited = vec![vec![false; max_x]; max_y];
+        let mut sinks = Vec::new();
+        let mut sink_ids = vec![vec![None; max_x]; max_y];
+        let mut n_plateau = 0;
+        for y in 0..max_y {
+            for x in 0..max_x {
+                if visited[y][x] || downhill[y][x].is_some() {
+                    continue;
+                }
+                // collect the plateau of equal height points connected to this point
+                let plateau = Self::plateau(heights, Point(x, y));
+                for &Point(px, py) in plateau.iter() {
+                    visited[py][px] = true;
+                }
+                let exits: Vec<_> = plateau
+                    .iter()
+                    .copied()
+                    .filter(|&Point(px, py)| downhill[py][px].is_some())
+                    .collect();
+
+                if exits.is_empty() {
+                    // no way down from the plateau: smoke settles in it as a sink
+                    for &Point(px, py) in plateau.iter() {
+                        sink_ids[py][px] = Some(sinks.len());
+                    }
+                    sinks.push(Sink {
+                        low_pt: Point(x, y),
+                        height: heights[y][x],
+                        size: plateau.len(),
+                        catchment: 0,
+                    });
+                } else {
+                    // breadth first search from the exits to direct each flat point
+                    // towards its nearest exit
+                    let mut queue: VecDeque<_> = exits.into_iter().collect();
+                    while let Some(pt) = queue.pop_front() {
+                        for other in surroundings(heights, pt) {
+                            let Point(ox, oy) = other;
+                            if height(other) == height(pt) && downhill[oy][ox].is_none() {
+                                downhill[oy][ox] = Some(pt);
+                                n_plateau += 1;
+                                queue.push_back(other);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        // follow the flow from each point to find the sink it drains to
+        for y in 0..max_y {
+            for x in 0..max_x {
+                let mut path = Vec::new();
+                let mut pt = Point(x, y);
+                let sink_id = loop {
+                    let Point(px, py) = pt;
+                    if let Some(sink_id) = sink_ids[py][px] {
+                        break sink_id;
+                    }
+                    path.push(pt);
+                    pt = downhill[py][px].expect("Expected non sink points to flow downhill");
+                };
+                // memorise the sink for every point along the path
+                for Point(px, py) in path {
+                    sink_ids[py][px] = Some(sink_id);
+                }
+                sinks[sink_id].catchment += 1;
+            }
+        }
+
+        Self {
+            sink_ids: sink_ids
+                .into_iter()
+                .map(|row| row.into_iter().map(Option::unwrap).collect())
+                .collect(),
+            sinks,
+            n_tied,
+            n_plateau,
+        }
+    }
+
+    /// Collect the plateau of equal height points connected to the given point.
+    fn plateau(heights: &[&[u8]], start: Point) -> Vec<Point> {
+        let Point(x, y) = start;
+        let mut plateau = vec![start];
+        let mut visited = HashSet::new();
+        visited.insert(start);
+        let mut i = 0;
+        while i < plateau.len() {
+            for other in surroundings(heights, plateau[i]) {
+                let Point(ox, oy) = other;
+                if heights[oy][ox] == heights[y][x] && visited.insert(other) {
+                    plateau.push(other);
+                }
+            }
+            i += 1;
+        }
+        plateau
+    }
+}
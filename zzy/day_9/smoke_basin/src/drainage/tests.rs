@@ -0,0 +1,76 @@
+//!
+//! Anti-Helmet
+//! Advent of Code
+//! Day 9: Smoke Basin
+//! Unit Tests
+//!
+
+use super::*;
+use crate::parse_heights;
+
+/// Analyse the drainage of the 2D heights given as lines of digits.
+fn analyse(input: &str) -> Drainage {
+    let heights = parse_heights(input);
+    let heights: Vec<_> = heights.iter().map(|row| row.as_slice()).collect();
+    Drainage::new(&heights)
+}
+
+/// Catchments of the sinks of the given drainage, indexed by sink id.
+fn catchments(drainage: &Drainage) -> Vec<usize> {
+    drainage.sinks.iter().map(|sink| sink.catchment).collect()
+}
+
+#[test]
+fn test_drainage_plateau() {
+    // flat points drain across the plateau to its exit
+    let drainage = analyse("3332");
+    assert_eq!(
+        drainage.sinks,
+        vec![Sink {
+            low_pt: Point(3, 0),
+            height: 2,
+            size: 1,
+            catchment: 4,
+        }]
+    );
+    assert_eq!(drainage.n_plateau, 2);
+
+    // plateaus without any exits are sinks in their entirety
+    let drainage = analyse("44\n44");
+    assert_eq!(drainage.sinks.len(), 1);
+    assert_eq!(
+        (drainage.sinks[0].size, drainage.sinks[0].catchment),
+        (4, 4)
+    );
+    assert_eq!(drainage.n_plateau, 0);
+}
+
+#[test]
+fn test_drainage_ties() {
+    // ties between left & right neighbours drain left
+    let drainage = analyse("151");
+    assert_eq!(drainage.sink_ids, vec![vec![0, 0, 1]]);
+    assert_eq!(catchments(&drainage), vec![2, 1]);
+    assert_eq!(drainage.n_tied, 1);
+
+    // ties between up & down neighbours drain up
+    let drainage = analyse("1\n5\n1");
+    assert_eq!(drainage.sink_ids, vec![vec![0], vec![0], vec![1]]);
+    assert_eq!(drainage.n_tied, 1);
+
+    // only neighbours tied for the steepest descent count as ties
+    let drainage = analyse("152");
+    assert_eq!(drainage.sink_ids, vec![vec![0, 0, 1]]);
+    assert_eq!(drainage.n_tied, 0);
+}
+
+#[test]
+fn test_drainage_walls() {
+    // walls of height 9 drain like any other point instead of blocking smoke
+    let drainage = analyse("19991");
+    assert_eq!(drainage.sink_ids, vec![vec![0, 0, 0, 1, 1]]);
+    assert_eq!(catchments(&drainage), vec![3, 2]);
+    // the middle of the wall is flat & drains towards the nearest exit on its left
+    assert_eq!(drainage.n_plateau, 1);
+    assert_eq!(drainage.n_tied, 0);
+}
@@ -1,17 +1,26 @@
-use std::cell::RefCell;
-use std::collections::HashSet;
-///!
-///! Anti-Helmet
-///! Advent of Code
-///! Day 9: Smoke Basin
-///!
-use std::io::{stdin, Read};
-use std::rc::Rc;
+//!
+//! Anti-Helmet
+//! Advent of Code
+//! Day 9: Smoke Basin
+//!
+
+use std::env;
+use std::fs::File;
+use std::io::{stdin, BufWriter, Read};
+
+mod basin;
+mod drainage;
+mod render;
+use basin::BasinMap;
+use drainage::Drainage;
 
 /// Represent a (x, y) point in 2D space
-#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
 struct Point(usize, usize);
 
+/// Characters used to draw basin labels, cycling if there are more basins than characters.
+const LABEL_CHARS: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
 /// Extract the surrounding points from the given point in the given 2D heights.
 /// Surrounding are defined as the that on the top, bottom, left, right of the given
 /// point. If a surrounding point is not defined for the given point in heights
@@ -51,59 +60,16 @@ fn surroundings(heights: &[&[u8]], Point(x, y): Point) -> Vec<Point> {
     .iter()
     // flatten to remove the wrapping option & discard undefined points
     .flatten()
-    .map(|&pt| pt)
+    .copied()
     .collect()
 }
 
-/// Measure the size of the basin specified by is lowest point in given 2D heights.
-/// Avoids exploring any points that are specified as already explored in explored set.
-/// Returns the no. of points that make up the basin
-fn measure_basin(
-    heights: &[&[u8]],
-    lowest_pt: Point,
-    explored: Rc<RefCell<HashSet<Point>>>,
-) -> u32 {
-    // collect basin points in the immediate surrounding of the given point
-    let Point(lowest_x, lowest_y) = lowest_pt;
-    let basin_pts = surroundings(heights, lowest_pt)
-        .into_iter()
-        .filter(|pt| !explored.borrow().contains(pt))
-        // surrounding basin points < height 9 and must be 1 height higher than lowest point
-        .filter(|&Point(other_x, other_y)| {
-            let basin_height = heights[other_y][other_x];
-            basin_height < 9 && basin_height > heights[lowest_y][lowest_x]
-        });
-
-    // mark lowest point as explored
-    explored.borrow_mut().insert(lowest_pt);
-
-    // recursively calculate total no. of basin points
-    basin_pts
-        .map(|pt| measure_basin(heights, pt, explored.clone()))
-        // +1 to account for lowest point
-        .sum::<u32>()
-        + 1
-}
-
-/// Check if the given point is considered a low point in the given 2D heights.
-fn is_low(heights: &[&[u8]], point: Point) -> bool {
-    let Point(x, y) = point;
-    // compare current with all surrounding points to check if its lower than all.
-    surroundings(heights, point)
-        .into_iter()
-        .all(|Point(other_x, other_y)| heights[y][x] < heights[other_y][other_x])
-}
-
-fn main() {
-    // read smoke flow heights from stdin
-    let mut input = String::new();
-    stdin()
-        .read_to_string(&mut input)
-        .expect("Failed to read smoke flow height map into string");
-    // heights is indexed in y, x order.
-    let heights: Vec<_> = input
+/// Parse 2D heights indexed in y, x order from the given lines of digits.
+/// Panics if a height is not a digit.
+fn parse_heights(input: &str) -> Vec<Vec<u8>> {
+    input
         .trim_end()
-        .split("\n")
+        .split('\n')
         .map(|line| {
             line.chars()
                 .map(|c| {
@@ -113,32 +79,95 @@ fn main() {
                 })
                 .collect::<Vec<_>>()
         })
-        .collect();
+        .collect()
+}
+
+fn main() {
+    // parse options from command line arguments:
+    // --basins to print the size, low point and bounds of each basin with a basin label map.
+    // --drainage to print the catchment of each sink that smoke drains to.
+    // --ppm=PATH to render the height map with basins coloured as a portable pixmap.
+    // --scale=N to draw each point as N x N pixels when rendering (default: 4).
+    let (mut show_basins, mut show_drainage) = (false, false);
+    let (mut ppm_path, mut scale) = (None, 4);
+    for arg in env::args().skip(1) {
+        match arg.split_once('=').unwrap_or((&arg, "")) {
+            ("--basins", _) => show_basins = true,
+            ("--drainage", _) => show_drainage = true,
+            ("--ppm", path) => ppm_path = Some(path.to_string()),
+            ("--scale", n) => {
+                scale = n.parse().expect("Failed to parse scale as unsigned int");
+                assert!(scale >= 1, "Scale must be >= 1");
+            }
+            _ => panic!("Unsupported option: {}", arg),
+        }
+    }
+
+    // read smoke flow heights from stdin
+    let mut input = String::new();
+    stdin()
+        .read_to_string(&mut input)
+        .expect("Failed to read smoke flow height map into string");
+    let heights = parse_heights(&input);
     // reconstruct heights as a 2D slice
     let heights: &Vec<&[u8]> = &heights.iter().map(|v| v.as_slice()).collect();
-    assert!(heights.len() >= 1); // check that heights is 2D
+    assert!(!heights.is_empty()); // check that heights is 2D
 
-    // generate all possible points on 2D space defined by heights
-    let max_y = heights.len();
-    let max_x = heights[0].len();
-    let all_points = (0..max_y).flat_map(|y| (0..max_x).map(|x| (x, y)).collect::<Vec<_>>());
+    // label basins separated by walls
+    let basin_map = BasinMap::new(heights);
+    if show_basins {
+        for (label, basin) in basin_map.basins.iter().enumerate() {
+            println!(
+                "basin {}: size {}, low point {:?}, bounds {:?}..={:?}",
+                label, basin.size, basin.low_pt, basin.min_pt, basin.max_pt
+            );
+        }
+        for row in basin_map.labels.iter() {
+            let line: String = row
+                .iter()
+                .map(|label| match label {
+                    Some(label) => LABEL_CHARS[label % LABEL_CHARS.len()] as char,
+                    None => '.',
+                })
+                .collect();
+            println!("{}", line);
+        }
+    }
 
-    // find low points in smoke flow
-    let low_pts = all_points
-        .map(|(x, y)| Point(x, y))
-        .filter(|&pt| is_low(&heights, pt));
+    if let Some(path) = ppm_path {
+        let mut file = BufWriter::new(File::create(&path).expect("Failed to create PPM file"));
+        render::write_ppm(&mut file, heights, &basin_map, scale).expect("Failed to write PPM file");
+    }
 
-    // collect basin sizes form lowest points
-    let mut basin_sizes: Vec<u32> = low_pts
-        .map(|low_pt| measure_basin(&heights, low_pt, Rc::new(RefCell::new(HashSet::new()))))
-        .collect();
+    // analyse where smoke drains to under steepest descent
+    if show_drainage {
+        let drainage = Drainage::new(heights);
+        for (sink_id, sink) in drainage.sinks.iter().enumerate() {
+            println!(
+                "sink {}: low point {:?}, height {}, size {}, catchment {}",
+                sink_id, sink.low_pt, sink.height, sink.size, sink.catchment
+            );
+        }
+        for row in drainage.sink_ids.iter() {
+            let line: String = row
+                .iter()
+                .map(|sink_id| LABEL_CHARS[sink_id % LABEL_CHARS.len()] as char)
+                .collect();
+            println!("{}", line);
+        }
+        println!(
+            "{} tied descents, {} points drained across plateaus",
+            drainage.n_tied, drainage.n_plateau
+        );
+    }
 
     // select top 3 basin sizes
+    let mut basin_sizes: Vec<_> = basin_map.basins.iter().map(|basin| basin.size).collect();
     basin_sizes.sort_unstable();
     basin_sizes.reverse();
 
     println!(
         "Risk sum: {}",
-        basin_sizes[..3].iter().fold(1, |left, &right| left * right)
+        basin_sizes.iter().take(3).product::<usize>()
     );
 }
@@ -0,0 +1,85 @@
+//!
+//! Anti-Helmet
+//! Advent of Code
+//! Day 9: Smoke Basin
+//! Rendering
+//!
+
+use crate::basin::{BasinMap, WALL_HEIGHT};
+use crate::Point;
+use std::io::{self, Write};
+
+#[cfg(test)]
+mod tests;
+
+type Rgb = [u8; 3];
+
+/// Colour used to draw walls separating basins.
+const WALL_COLOUR: Rgb = [24, 24, 64];
+/// Colour used to highlight the low point of each basin.
+const LOW_POINT_COLOUR: Rgb = [255, 255, 255];
+
+/// Pick a distinct colour for the basin with the given label by spacing hues
+/// around the colour wheel by the golden angle.
+fn basin_colour(label: usize) -> Rgb {
+    let hue = (label as f64 * 0.618_033_988_75).fract() * 6.0;
+    // convert hue to rgb at full saturation & value
+    let rising = hue.fract();
+    let (r, g, b) = match hue as u8 {
+        0 => (1.0, rising, 0.0),
+        1 => (1.0 - rising, 1.0, 0.0),
+        2 => (0.0, 1.0, rising),
+        3 => (0.0, 1.0 - rising, 1.0),
+        4 => (rising, 0.0, 1.0),
+        _ => (1.0, 0.0, 1.0 - rising),
+    };
+    [r, g, b].map(|channel| (channel * 255.0) as u8)
+}
+
+/// Shade the given colour by the given height, darkening lower points.
+fn shade(colour: Rgb, height: u8) -> Rgb {
+    // blend basin colour with a grayscale height ramp
+    let gray = 64.0 + 128.0 * height as f64 / WALL_HEIGHT as f64;
+    colour.map(|channel| ((channel as f64 * 0.5 + gray * 0.5) * (0.4 + 0.6 * gray / 192.0)) as u8)
+}
+
+/// Write the given 2D heights as a binary portable pixmap (PPM), drawing each point
+/// as a scale x scale square. Points are shaded by height and coloured by the basin
+/// they belong to in the given basin map, with walls and basin low points highlighted.
+/// Panics if scale is 0, which would render an empty image.
+pub fn write_ppm<W: Write>(
+    out: &mut W,
+    heights: &[&[u8]],
+    basin_map: &BasinMap,
+    scale: usize,
+) -> io::Result<()> {
+    let max_y = heights.len();
+    let max_x = heights.first().map_or(0, |row| row.len());
+    assert!(scale >= 1, "Expected scale to be at least 1");
+
+    // compute colour of each point
+    let colours: Vec<Vec<Rgb>> = (0..max_y)
+        .map(|y| {
+            (0..max_x)
+                .map(|x| match basin_map.labels[y][x] {
+                    None => WALL_COLOUR,
+                    Some(label) if basin_map.basins[label].low_pt == Point(x, y) => {
+                        LOW_POINT_COLOUR
+                    }
+                    Some(label) => shade(basin_colour(label), heights[y][x]),
+                })
+                .collect()
+        })
+        .collect();
+
+    writeln!(out, "P6")?;
+    writeln!(out, "{} {}", max_x * scale, max_y * scale)?;
+    writeln!(out, "255")?;
+    for row in colours.iter() {
+        let pixels: Vec<u8> = row.iter().flat_map(|colour| colour.repeat(scale)).collect();
+        for _ in 0..scale {
+            out.write_all(&pixels)?;
+        }
+    }
+    Ok(())
+}
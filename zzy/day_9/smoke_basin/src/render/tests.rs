@@ -0,0 +1,49 @@
+//!
+//! Anti-Helmet
+//! Advent of Code
+//! Day 9: Smoke Basin
+//! Unit Tests
+//!
+
+use super::*;
+
+/// Render the given rows of 2D heights as a PPM image at the given scale.
+fn render(heights: &[&[u8]], scale: usize) -> Vec<u8> {
+    let basin_map = BasinMap::new(heights);
+    let mut out = Vec::new();
+    write_ppm(&mut out, heights, &basin_map, scale).unwrap();
+    out
+}
+
+#[test]
+fn test_basin_colour() {
+    assert_eq!(basin_colour(0), [255, 0, 0]);
+    assert_ne!(basin_colour(1), basin_colour(2));
+}
+
+#[test]
+fn test_write_ppm() {
+    let heights: [&[u8]; 2] = [&[1, 9, 3], &[2, 9, 0]];
+    let out = render(&heights, 2);
+    let header = b"P6\n6 4\n255\n";
+    assert_eq!(&out[..header.len()], header);
+    let pixels: Vec<_> = out[header.len()..].chunks(3).collect();
+    assert_eq!(pixels.len(), 6 * 4);
+
+    // each point is drawn as a 2 x 2 square
+    let pixel = |x: usize, y: usize| pixels[y * 6 + x];
+    assert_eq!(pixel(0, 0), LOW_POINT_COLOUR);
+    assert_eq!(pixel(1, 1), LOW_POINT_COLOUR);
+    assert_eq!(pixel(2, 0), WALL_COLOUR);
+    assert_eq!(pixel(3, 3), WALL_COLOUR);
+    assert_eq!(pixel(4, 3), LOW_POINT_COLOUR);
+    // other basin points are shaded in their basin's colour
+    assert_eq!(pixel(0, 2), shade(basin_colour(0), 2));
+    assert_eq!(pixel(5, 0), shade(basin_colour(1), 3));
+}
+
+#[test]
+#[should_panic]
+fn test_write_ppm_zero_scale() {
+    render(&[&[1, 2]], 0);
+}